@@ -0,0 +1,243 @@
+use super::{
+  computed::Memo,
+  setter::{OnChange, SetterStyle},
+  tracker::DirtyBit,
+};
+use darling::FromMeta;
+use quote::{quote, ToTokens};
+
+#[derive(Clone, Copy)]
+pub struct BitRange {
+  pub start: u32,
+  pub end: u32,
+}
+
+impl FromMeta for BitRange {
+  fn from_string(value: &str) -> darling::Result<Self> {
+    let range = syn::parse_str::<syn::ExprRange>(value)?;
+    let bound = |expr: Option<&syn::Expr>| -> darling::Result<u32> {
+      match expr {
+        Some(syn::Expr::Lit(syn::ExprLit {
+          lit: syn::Lit::Int(lit),
+          ..
+        })) => Ok(lit.base10_parse()?),
+        _ => Err(darling::Error::custom(
+          "expected a range of integer literals, e.g. \"0..4\" or \"0..=3\"",
+        )),
+      }
+    };
+    let start = bound(range.from.as_deref())?;
+    let end = match range.limits {
+      syn::RangeLimits::HalfOpen(_) => bound(range.to.as_deref())?,
+      syn::RangeLimits::Closed(_) => bound(range.to.as_deref())? + 1,
+    };
+    if start >= end {
+      return Err(darling::Error::custom("expected a non-empty bit range"));
+    }
+    Ok(Self { start, end })
+  }
+}
+
+#[derive(FromMeta)]
+pub struct BitsOptions {
+  pub name: syn::Ident,
+  pub range: Option<BitRange>,
+  pub bit: Option<u32>,
+  pub ty: Option<syn::Type>,
+  pub style: Option<SetterStyle>,
+}
+
+impl BitsOptions {
+  pub fn to_field_bits(
+    &self,
+    field_name: &syn::Ident,
+    field_ty: &syn::Type,
+  ) -> darling::Result<FieldBits> {
+    let field_bits = match field_ty {
+      syn::Type::Path(path) => path.path.get_ident().and_then(|ident| {
+        Some(match ident.to_string().as_str() {
+          "u8" | "i8" => 8,
+          "u16" | "i16" => 16,
+          "u32" | "i32" => 32,
+          "u64" | "i64" => 64,
+          "u128" | "i128" => 128,
+          "usize" | "isize" => usize::BITS,
+          _ => return None,
+        })
+      }),
+      _ => None,
+    }
+    .ok_or_else(|| darling::Error::custom("`bits` expects an integer field").with_span(field_ty))?;
+
+    let (range, ty) = match (self.range, self.bit) {
+      (Some(range), None) => (range, self.ty.clone().unwrap_or_else(|| field_ty.clone())),
+      (None, Some(bit)) => (
+        BitRange {
+          start: bit,
+          end: bit + 1,
+        },
+        self.ty.clone().unwrap_or_else(|| syn::parse_quote!(bool)),
+      ),
+      _ => {
+        return Err(
+          darling::Error::custom("expected exactly one of `range` or `bit`").with_span(&self.name),
+        )
+      }
+    };
+    let ty_bits = match &ty {
+      syn::Type::Path(path) => path.path.get_ident().and_then(|ident| {
+        Some(match ident.to_string().as_str() {
+          "bool" => 1,
+          "u8" => 8,
+          "u16" => 16,
+          "u32" => 32,
+          "u64" => 64,
+          "u128" => 128,
+          "usize" => usize::BITS,
+          _ => return None,
+        })
+      }),
+      _ => None,
+    }
+    .ok_or_else(|| {
+      darling::Error::custom("`bits` expects an unsigned integer or `bool` type").with_span(&ty)
+    })?;
+    if range.end - range.start > ty_bits {
+      return Err(
+        darling::Error::custom(format!(
+          "bit range {}..{} does not fit in the {ty_bits} bits of `{}`",
+          range.start,
+          range.end,
+          ty.to_token_stream()
+        ))
+        .with_span(&ty),
+      );
+    }
+    if range.end > field_bits {
+      return Err(
+        darling::Error::custom(format!(
+          "bit range {}..{} is out of the {field_bits} bits of the field",
+          range.start, range.end
+        ))
+        .with_span(&self.name),
+      );
+    }
+
+    Ok(FieldBits {
+      name: self.name.clone(),
+      range,
+      ty,
+      field_ty: field_ty.clone(),
+      style: self.style,
+      field_name: field_name.clone(),
+      getter: None,
+      setter: None,
+      track: None,
+      memo: Memo::default(),
+      on_change: None,
+      getter_attrs: Vec::new(),
+      setter_attrs: Vec::new(),
+    })
+  }
+}
+
+pub struct FieldBits {
+  pub name: syn::Ident,
+  pub range: BitRange,
+  pub ty: syn::Type,
+  pub field_ty: syn::Type,
+  pub style: Option<SetterStyle>,
+  pub field_name: syn::Ident,
+  pub getter: Option<(syn::Visibility, syn::Ident)>,
+  pub setter: Option<(syn::Visibility, syn::Ident, SetterStyle)>,
+  pub track: Option<DirtyBit>,
+  pub memo: Memo,
+  pub on_change: Option<OnChange>,
+  pub getter_attrs: Vec<syn::Attribute>,
+  pub setter_attrs: Vec<syn::Attribute>,
+}
+
+impl FieldBits {
+  fn is_bool(&self) -> bool {
+    matches!(&self.ty, syn::Type::Path(path) if path.path.is_ident("bool"))
+  }
+}
+
+impl ToTokens for FieldBits {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let field_name = &self.field_name;
+    let field_ty = &self.field_ty;
+    let ty = &self.ty;
+    let start = self.range.start;
+    let width = self.range.end - self.range.start;
+    let max = if width == 128 {
+      u128::MAX
+    } else {
+      (1u128 << width) - 1
+    };
+    let mask = quote!((#max as #field_ty));
+
+    if let Some((vis, fn_name)) = &self.getter {
+      let value = quote!((self.#field_name >> #start) & #mask);
+      let result = if self.is_bool() {
+        quote!(#value != 0)
+      } else {
+        quote!((#value) as #ty)
+      };
+      let attrs = &self.getter_attrs;
+      tokens.extend(quote! {
+        #(#attrs)*
+        #vis fn #fn_name(&self) -> #ty {
+          #result
+        }
+      });
+    }
+
+    if let Some((vis, fn_name, style)) = &self.setter {
+      let name = self.name.to_string();
+      let check = (!self.is_bool()).then(|| {
+        let msg = format!(
+          "value overflows the bits `{name}` ({}..{}) of field `{field_name}`",
+          self.range.start, self.range.end
+        );
+        quote! {
+          ::core::assert!(
+            ::core::convert::TryInto::<u128>::try_into(val).map_or(false, |val| val <= #max),
+            #msg,
+          );
+        }
+      });
+      let attrs = &self.setter_attrs;
+      let mark = self.track.as_ref().map(DirtyBit::mark);
+      let memo = &self.memo;
+      // the hook sees the old and new values of the whole field, copied as the field is an integer
+      let notify = self.on_change.as_ref().map(|on_change| {
+        on_change.notify(field_name, &quote!(&{ self.#field_name }), &quote!(val))
+      });
+      let assign = quote! {
+        #check
+        let val = val as #field_ty;
+        let val = (self.#field_name & !(#mask << #start)) | ((val & #mask) << #start);
+        #notify
+        self.#field_name = val;
+        #mark
+        #memo
+      };
+      tokens.extend(match self.style.unwrap_or(*style) {
+        SetterStyle::Ref | SetterStyle::Cow => quote! {
+          #(#attrs)*
+          #vis fn #fn_name(&mut self, val: #ty) {
+            #assign
+          }
+        },
+        _ => quote! {
+          #(#attrs)*
+          #vis fn #fn_name(mut self, val: #ty) -> Self {
+            #assign
+            self
+          }
+        },
+      });
+    }
+  }
+}
@@ -0,0 +1,127 @@
+use quote::{format_ident, quote};
+
+#[derive(Clone, Copy)]
+enum CollectionKind {
+  Seq,
+  Set,
+  Map,
+}
+
+#[derive(Clone)]
+pub struct Collection {
+  kind: CollectionKind,
+  mutable: bool,
+  args: Vec<syn::Type>,
+}
+
+impl Collection {
+  pub fn from_type(ty: &syn::Type) -> Option<Self> {
+    let syn::Type::Path(path) = ty else {
+      return None;
+    };
+    let segment = path.path.segments.last()?;
+    let (kind, mutable, arity) = match segment.ident.to_string().as_str() {
+      "Vec" | "VecDeque" | "LinkedList" => (CollectionKind::Seq, true, 1),
+      "BinaryHeap" => (CollectionKind::Seq, false, 1),
+      "HashSet" | "BTreeSet" | "IndexSet" => (CollectionKind::Set, false, 1),
+      "HashMap" | "BTreeMap" | "IndexMap" => (CollectionKind::Map, true, 2),
+      _ => return None,
+    };
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+      return None;
+    };
+    let args = args
+      .args
+      .iter()
+      .filter_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+      })
+      .take(arity)
+      .collect::<Vec<_>>();
+    (args.len() == arity).then_some(Self {
+      kind,
+      mutable,
+      args,
+    })
+  }
+
+  fn item(&self) -> proc_macro2::TokenStream {
+    match (self.kind, self.args.as_slice()) {
+      (CollectionKind::Map, [k, v]) => quote!((&'_ #k, &'_ #v)),
+      (_, [t, ..]) => quote!(&'_ #t),
+      _ => unreachable!(),
+    }
+  }
+
+  fn item_mut(&self) -> proc_macro2::TokenStream {
+    match (self.kind, self.args.as_slice()) {
+      (CollectionKind::Map, [k, v]) => quote!((&'_ #k, &'_ mut #v)),
+      (_, [t, ..]) => quote!(&'_ mut #t),
+      _ => unreachable!(),
+    }
+  }
+
+  pub fn fn_names(&self, fn_name: &syn::Ident) -> Vec<syn::Ident> {
+    let mut names = vec![fn_name.clone()];
+    if self.mutable {
+      names.push(format_ident!("{}_mut", fn_name));
+    }
+    names.push(format_ident!("{}_len", fn_name));
+    names.push(format_ident!("{}_is_empty", fn_name));
+    names
+  }
+
+  pub fn to_getters(
+    &self,
+    vis: &syn::Visibility,
+    fn_name: &syn::Ident,
+    field_name: &syn::Ident,
+    attrs: &[syn::Attribute],
+    where_clause: Option<&syn::WhereClause>,
+  ) -> proc_macro2::TokenStream {
+    let item = self.item();
+    let len_fn = format_ident!("{}_len", fn_name);
+    let is_empty_fn = format_ident!("{}_is_empty", fn_name);
+
+    quote! {
+      #(#attrs)*
+      #vis fn #fn_name(&self) -> impl ::core::iter::Iterator<Item = #item> + '_ #where_clause {
+        self.#field_name.iter()
+      }
+
+      #(#attrs)*
+      #vis fn #len_fn(&self) -> usize #where_clause {
+        self.#field_name.len()
+      }
+
+      #(#attrs)*
+      #vis fn #is_empty_fn(&self) -> bool #where_clause {
+        self.#field_name.is_empty()
+      }
+    }
+  }
+
+  /// The `*_mut` getter, which is `None` for the collections without `iter_mut`.
+  pub fn to_mut_getter(
+    &self,
+    vis: &syn::Visibility,
+    fn_name: &syn::Ident,
+    field_name: &syn::Ident,
+    attrs: &[syn::Attribute],
+    where_clause: Option<&syn::WhereClause>,
+    mark: proc_macro2::TokenStream,
+  ) -> Option<proc_macro2::TokenStream> {
+    self.mutable.then(|| {
+      let item_mut = self.item_mut();
+      let iter_mut_fn = format_ident!("{}_mut", fn_name);
+      quote! {
+        #(#attrs)*
+        #vis fn #iter_mut_fn(&mut self) -> impl ::core::iter::Iterator<Item = #item_mut> + '_ #where_clause {
+          #mark
+          self.#field_name.iter_mut()
+        }
+      }
+    })
+  }
+}
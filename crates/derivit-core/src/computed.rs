@@ -0,0 +1,177 @@
+use super::tracker::serde_skip;
+use darling::FromMeta;
+use quote::{format_ident, quote, ToTokens};
+
+#[derive(FromMeta, Clone)]
+pub struct ComputedOptions {
+  pub name: syn::Ident,
+  pub ty: syn::Type,
+  pub expr: syn::Expr,
+  pub vis: Option<syn::Visibility>,
+  pub doc: Option<String>,
+  #[darling(default)]
+  pub memo: bool,
+}
+
+impl ComputedOptions {
+  /// The `OnceCell` field of the memoised value.
+  pub fn cell(&self) -> Option<syn::Ident> {
+    self
+      .memo
+      .then(|| format_ident!("__viewit_memo_{}", self.name))
+  }
+
+  pub fn to_getter(
+    &self,
+    vis: syn::Visibility,
+    fn_name: syn::Ident,
+    attrs: Vec<syn::Attribute>,
+  ) -> ComputedGetter {
+    ComputedGetter {
+      vis,
+      fn_name,
+      attrs,
+      ty: self.ty.clone(),
+      expr: self.expr.clone(),
+      doc: self.doc.clone(),
+      cell: self.cell(),
+    }
+  }
+}
+
+pub struct ComputedGetter {
+  pub vis: syn::Visibility,
+  pub fn_name: syn::Ident,
+  pub attrs: Vec<syn::Attribute>,
+  pub ty: syn::Type,
+  pub expr: syn::Expr,
+  pub doc: Option<String>,
+  /// The `OnceCell` field which memoises the value.
+  pub cell: Option<syn::Ident>,
+}
+
+impl ComputedGetter {
+  /// The cell field of type `cell_ty`, which is skipped by serde as the dirty bitset is.
+  pub fn to_field(
+    &self,
+    cell_ty: &syn::Ident,
+    struct_attrs: &[syn::Attribute],
+  ) -> Option<syn::Field> {
+    let cell = self.cell.as_ref()?;
+    let ty = &self.ty;
+    let mut attrs = vec![syn::parse_quote!(#[doc(hidden)])];
+    attrs.extend(serde_skip(struct_attrs));
+    Some(syn::Field {
+      attrs,
+      vis: syn::Visibility::Inherited,
+      ident: Some(cell.clone()),
+      colon_token: Some(Default::default()),
+      ty: syn::parse_quote!(#cell_ty<#ty>),
+    })
+  }
+}
+
+/// The name of the cell newtype of the memoised values of `struct_name`.
+pub fn memo_cell_ty(struct_name: &syn::Ident) -> syn::Ident {
+  format_ident!("{}MemoCell", struct_name)
+}
+
+/// Generates the cell newtype, which must be placed outside of the impl block.
+pub fn memo_cell(cell_ty: &syn::Ident) -> proc_macro2::TokenStream {
+  quote! {
+    /// The cell of a memoised value, which is a cache, so it is empty when cloned,
+    /// and it does not change the comparisons and hashing derived for the struct.
+    #[doc(hidden)]
+    struct #cell_ty<T>(::core::cell::OnceCell<T>);
+
+    impl<T> ::core::default::Default for #cell_ty<T> {
+      fn default() -> Self {
+        Self(::core::cell::OnceCell::new())
+      }
+    }
+
+    impl<T> ::core::clone::Clone for #cell_ty<T> {
+      fn clone(&self) -> Self {
+        ::core::default::Default::default()
+      }
+    }
+
+    impl<T> ::core::fmt::Debug for #cell_ty<T> {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(::core::stringify!(#cell_ty))
+      }
+    }
+
+    impl<T> ::core::cmp::PartialEq for #cell_ty<T> {
+      fn eq(&self, _: &Self) -> bool {
+        true
+      }
+    }
+
+    impl<T> ::core::cmp::Eq for #cell_ty<T> {}
+
+    impl<T> ::core::cmp::PartialOrd for #cell_ty<T> {
+      fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+        ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+      }
+    }
+
+    impl<T> ::core::cmp::Ord for #cell_ty<T> {
+      fn cmp(&self, _: &Self) -> ::core::cmp::Ordering {
+        ::core::cmp::Ordering::Equal
+      }
+    }
+
+    impl<T> ::core::hash::Hash for #cell_ty<T> {
+      fn hash<H: ::core::hash::Hasher>(&self, _: &mut H) {}
+    }
+  }
+}
+
+/// The cells of the memoised values, which every generated mutator resets.
+#[derive(Clone, Default)]
+pub struct Memo {
+  pub cells: Vec<syn::Ident>,
+}
+
+impl Memo {
+  /// Resets the cells of `this`, which is `self` except in the pin projections.
+  pub fn reset(&self, this: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let cells = &self.cells;
+    quote!(#(#this.#cells = ::core::default::Default::default();)*)
+  }
+}
+
+impl ToTokens for Memo {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    tokens.extend(self.reset(&quote!(self)));
+  }
+}
+
+impl ToTokens for ComputedGetter {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let vis = &self.vis;
+    let fn_name = &self.fn_name;
+    let ty = &self.ty;
+    let expr = &self.expr;
+    let attrs = &self.attrs;
+    let doc = self.doc.as_ref().map(|doc| quote!(#[doc = #doc]));
+
+    tokens.extend(match &self.cell {
+      Some(cell) => quote! {
+        #doc
+        #(#attrs)*
+        #vis fn #fn_name(&self) -> &#ty {
+          self.#cell.0.get_or_init(|| #expr)
+        }
+      },
+      None => quote! {
+        #doc
+        #(#attrs)*
+        #vis fn #fn_name(&self) -> #ty {
+          #expr
+        }
+      },
+    });
+  }
+}
@@ -0,0 +1,93 @@
+use darling::FromMeta;
+use quote::format_ident;
+
+#[derive(FromMeta, Clone, Copy)]
+pub enum Endian {
+  #[darling(rename = "be")]
+  Big,
+  #[darling(rename = "le")]
+  Little,
+  #[darling(rename = "ne")]
+  Native,
+}
+
+impl Endian {
+  fn suffix(&self) -> &'static str {
+    match self {
+      Self::Big => "be",
+      Self::Little => "le",
+      Self::Native => "ne",
+    }
+  }
+}
+
+#[derive(Clone)]
+pub struct EndianConverter {
+  pub endian: Endian,
+  pub ty: syn::Type,
+}
+
+impl EndianConverter {
+  /// Checks the field is a `[u8; N]` array, and `N` matches the size of `ty`.
+  pub fn new(endian: Endian, ty: syn::Type, field_ty: &syn::Type) -> darling::Result<Self> {
+    let size = match &ty {
+      syn::Type::Path(path) => path.path.get_ident().and_then(|ident| {
+        Some(match ident.to_string().as_str() {
+          "u8" | "i8" => 1,
+          "u16" | "i16" => 2,
+          "u32" | "i32" | "f32" => 4,
+          "u64" | "i64" | "f64" => 8,
+          "u128" | "i128" => 16,
+          _ => return None,
+        })
+      }),
+      _ => None,
+    }
+    .ok_or_else(|| {
+      darling::Error::custom("`endian` expects a fixed size integer or float `ty`").with_span(&ty)
+    })?;
+
+    let len = match field_ty {
+      syn::Type::Array(arr) if matches!(&*arr.elem, syn::Type::Path(p) if p.path.is_ident("u8")) => {
+        match &arr.len {
+          syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+          }) => Some(lit.base10_parse::<usize>()?),
+          // the length is a const or a const generic, let the compiler check it.
+          _ => None,
+        }
+      }
+      _ => {
+        return Err(
+          darling::Error::custom("`endian` expects a `[u8; N]` field").with_span(field_ty),
+        )
+      }
+    };
+    if let Some(len) = len {
+      if len != size {
+        return Err(
+          darling::Error::custom(format!(
+            "`[u8; {len}]` cannot be converted to `{}`, which has {size} bytes",
+            quote::ToTokens::to_token_stream(&ty)
+          ))
+          .with_span(field_ty),
+        );
+      }
+    }
+
+    Ok(Self { endian, ty })
+  }
+
+  pub fn from_bytes(&self) -> syn::Path {
+    let ty = &self.ty;
+    let func = format_ident!("from_{}_bytes", self.endian.suffix());
+    syn::parse_quote!(#ty::#func)
+  }
+
+  pub fn to_bytes(&self) -> syn::Path {
+    let ty = &self.ty;
+    let func = format_ident!("to_{}_bytes", self.endian.suffix());
+    syn::parse_quote!(#ty::#func)
+  }
+}
@@ -1,5 +1,14 @@
-use darling::FromMeta;
-use quote::{quote, ToTokens};
+use super::{
+  collection::Collection,
+  computed::Memo,
+  endian::{Endian, EndianConverter},
+  lock::{Lock, Poison},
+  packed::assert_copy,
+  tracker::DirtyBit,
+  Attributes, FnWhereClause, IdentList, Inline,
+};
+use darling::{util::SpannedValue, FromMeta};
+use quote::{format_ident, quote, ToTokens};
 
 #[derive(Default, FromMeta, Clone, Copy)]
 pub enum Style {
@@ -30,20 +39,41 @@ pub struct FieldConverter {
 pub struct FieldGetterOptions {
   pub rename: Option<syn::Ident>,
   pub style: Option<Style>,
-  #[darling(default, rename = "skip")]
-  pub ignore: bool,
+  #[darling(rename = "skip")]
+  pub ignore: Option<bool>,
   pub vis: Option<syn::Visibility>,
   pub result: Option<GetterConverter>,
+  #[darling(default, rename = "where")]
+  pub where_clause: FnWhereClause,
+  #[darling(default)]
+  pub impl_where: FnWhereClause,
+  pub take: Option<bool>,
+  #[darling(default)]
+  pub iter: SpannedValue<bool>,
+  pub lock: Option<SpannedValue<Poison>>,
+  pub endian: Option<SpannedValue<Endian>>,
+  #[darling(default)]
+  pub index: SpannedValue<bool>,
+  pub ty: Option<syn::Type>,
+  #[darling(default)]
+  pub attrs: Attributes,
+  pub inline: Option<Inline>,
 }
 
 #[derive(FromMeta)]
+#[darling(default)]
 pub struct StructGetterOptions {
   pub prefix: Option<syn::Ident>,
-  #[darling(default)]
   pub style: Style,
-  #[darling(default, rename = "skip")]
+  #[darling(rename = "skip")]
   pub ignore: bool,
   pub vis_all: Option<syn::Visibility>,
+  pub take: bool,
+  pub group_view: bool,
+  pub allow_shadow: IdentList,
+  pub skip_types: Option<IdentList>,
+  pub attrs: Attributes,
+  pub inline: Inline,
 }
 
 impl Default for StructGetterOptions {
@@ -53,6 +83,31 @@ impl Default for StructGetterOptions {
       style: Style::Ref,
       ignore: false,
       vis_all: None,
+      take: false,
+      group_view: false,
+      allow_shadow: IdentList::default(),
+      skip_types: None,
+      attrs: Attributes::default(),
+      inline: Inline::default(),
+    }
+  }
+}
+
+const DEFAULT_SKIP_TYPES: &[&str] = &["PhantomData", "PhantomPinned"];
+
+impl StructGetterOptions {
+  /// Returns whether the accessors of a field of the type are skipped by default,
+  /// the type is matched by the last segment of its path.
+  pub fn is_skipped_type(&self, ty: &syn::Type) -> bool {
+    let syn::Type::Path(path) = ty else {
+      return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+      return false;
+    };
+    match &self.skip_types {
+      Some(types) => types.contains(&segment.ident),
+      None => DEFAULT_SKIP_TYPES.iter().any(|ty| segment.ident == ty),
     }
   }
 }
@@ -88,6 +143,17 @@ pub struct GetterConverter {
 }
 
 impl GetterConverter {
+  pub fn from_endian(endian: &EndianConverter) -> Self {
+    Self {
+      ty: Some(endian.ty.clone()),
+      converter: FieldConverter {
+        style: Some(Style::Move),
+        func: Some(endian.from_bytes()),
+      },
+      bound: FnGenerics::default(),
+    }
+  }
+
   pub fn to_getter_fn(
     &self,
     field_name: &syn::Ident,
@@ -138,28 +204,108 @@ pub struct FieldGetter {
   pub field_ty: syn::Type,
   pub style: Style,
   pub vis: syn::Visibility,
+  /// The visibility of the getters which write the field, `take_*`, `*_mut` and `write_*`.
+  pub mut_vis: syn::Visibility,
   pub fn_name: syn::Ident,
   pub converter: Option<GetterConverter>,
+  pub iter: Option<Collection>,
+  pub lock: Option<Lock>,
+  pub where_clause: Option<syn::WhereClause>,
+  pub impl_where: Option<syn::WhereClause>,
+  pub take: bool,
+  pub track: Option<DirtyBit>,
+  pub memo: Memo,
+  pub packed: bool,
+  pub attrs: Vec<syn::Attribute>,
+}
+
+/// Returns whether the type is `&'a T` or `Option<&'a T>`.
+fn is_shared_ref(ty: &syn::Type) -> bool {
+  match ty {
+    syn::Type::Reference(r) => r.mutability.is_none(),
+    syn::Type::Path(path) => {
+      let Some(segment) = path.path.segments.last() else {
+        return false;
+      };
+      match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if segment.ident == "Option" => matches!(
+          args.args.first(),
+          Some(syn::GenericArgument::Type(syn::Type::Reference(r))) if args.args.len() == 1 && r.mutability.is_none()
+        ),
+        _ => false,
+      }
+    }
+    syn::Type::Paren(paren) => is_shared_ref(&paren.elem),
+    _ => false,
+  }
+}
+
+impl FieldGetter {
+  fn take_fn(&self) -> syn::Ident {
+    format_ident!("take_{}", self.field_name)
+  }
+
+  pub fn fn_names(&self) -> Vec<syn::Ident> {
+    let mut names = match (&self.iter, &self.lock) {
+      (Some(collection), _) => collection.fn_names(&self.fn_name),
+      (None, Some(lock)) => lock.fn_names(&self.fn_name),
+      (None, None) => vec![self.fn_name.clone()],
+    };
+    if self.take {
+      names.push(self.take_fn());
+    }
+    names
+  }
 }
 
 impl ToTokens for FieldGetter {
   fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
     let vis = &self.vis;
+    let mut_vis = &self.mut_vis;
     let fn_name = &self.fn_name;
     let field_name = &self.field_name;
+    let attrs = &self.attrs;
+    let where_clause = self.where_clause.as_ref();
     let field_ty = self
       .converter
       .as_ref()
       .map(|conv| conv.ty.as_ref().unwrap_or(&self.field_ty))
       .unwrap_or(&self.field_ty);
+    let assert_copy = self.packed.then(|| assert_copy(&self.field_ty));
+    // take a reference to a copy of the field for the packed struct
+    let field_ref = if self.packed {
+      quote!(&{ self.#field_name })
+    } else {
+      quote!(&self.#field_name)
+    };
 
-    match &self.converter {
-      Some(converter) => {
+    match (&self.iter, &self.lock, &self.converter) {
+      (Some(collection), _, _) => {
+        // the `*_mut` getter marks the field dirty and resets the memoised values
+        let mark = self.track.as_ref().map(DirtyBit::mark);
+        let memo = &self.memo;
+        tokens.extend(collection.to_getters(vis, fn_name, field_name, attrs, where_clause));
+        tokens.extend(collection.to_mut_getter(
+          mut_vis,
+          fn_name,
+          field_name,
+          attrs,
+          where_clause,
+          quote!(#mark #memo),
+        ));
+      }
+      (None, Some(lock), _) => {
+        tokens.extend(lock.to_getters(vis, mut_vis, fn_name, field_name, attrs, where_clause));
+      }
+      (None, None, Some(converter)) => {
         let bound = converter.bound.bound.as_ref();
-        let result = match self.style {
+        let result = match converter.converter.style.unwrap_or(self.style) {
           Style::Ref => match &converter.converter.func {
             Some(conv) => quote! {
-              #conv(&self.#field_name)
+              #conv(#field_ref)
+            },
+            None if self.packed => quote! {
+              self.#field_name
             },
             None => quote! {
               &self.#field_name
@@ -177,28 +323,62 @@ impl ToTokens for FieldGetter {
 
         tokens.extend(match self.style {
           Style::Ref => quote! {
-            #[inline]
-            #vis fn #fn_name #bound (&self) -> #field_ty {
+            #(#attrs)*
+            #vis fn #fn_name #bound (&self) -> #field_ty #where_clause {
+              #assert_copy
               #result
             }
           },
           Style::Move => quote! {
-            #[inline]
-            #vis fn #fn_name #bound (self) ->  {
+            #(#attrs)*
+            #vis fn #fn_name #bound (self) -> #field_ty #where_clause {
+              #assert_copy
               #result
             }
           },
         });
       }
-      None => {
-        let style = self.style;
+      (None, None, None) => {
+        // the fields of the packed struct are always read by value, and the shared references
+        // are copied, so the getter returns them with their original lifetime
+        let style = if self.packed || is_shared_ref(&self.field_ty) {
+          Style::Move
+        } else {
+          self.style
+        };
         tokens.extend(quote! {
-            #[inline]
-            #vis fn #fn_name(&self) -> #style #field_ty {
+            #(#attrs)*
+            #vis fn #fn_name(&self) -> #style #field_ty #where_clause {
+              #assert_copy
               #style self.#field_name
             }
         });
       }
     }
+
+    if self.take {
+      let field_ty = &self.field_ty;
+      let take_fn = self.take_fn();
+      let mark = self.track.as_ref().map(DirtyBit::mark);
+      let memo = &self.memo;
+      let take = if self.packed {
+        quote! {
+          let val = self.#field_name;
+          self.#field_name = ::core::default::Default::default();
+          val
+        }
+      } else {
+        quote!(::core::mem::take(&mut self.#field_name))
+      };
+      tokens.extend(quote! {
+        #(#attrs)*
+        #mut_vis fn #take_fn(&mut self) -> #field_ty {
+          #assert_copy
+          #mark
+          #memo
+          #take
+        }
+      });
+    }
   }
 }
@@ -0,0 +1,162 @@
+use super::{
+  computed::Memo,
+  setter::{OnChange, SetterStyle},
+  tracker::DirtyBit,
+};
+use heck::ToUpperCamelCase;
+use quote::{format_ident, quote, ToTokens};
+
+#[derive(Clone)]
+pub struct GroupField {
+  pub field_name: syn::Ident,
+  pub field_ty: syn::Type,
+  pub track: Option<DirtyBit>,
+  pub on_change: Option<OnChange>,
+}
+
+impl GroupField {
+  /// Assigns the value of the variable named after the field.
+  pub(crate) fn assign(&self) -> proc_macro2::TokenStream {
+    let field_name = &self.field_name;
+    let val = quote!(#field_name);
+    let notify = self
+      .on_change
+      .as_ref()
+      .map(|on_change| on_change.notify(field_name, &quote!(&self.#field_name), &val));
+    let mark = self.track.as_ref().map(DirtyBit::mark);
+    quote! {
+      #notify
+      self.#field_name = #val;
+      #mark
+    }
+  }
+}
+
+pub struct FieldGroup {
+  pub name: syn::Ident,
+  pub struct_name: syn::Ident,
+  pub generics: syn::Generics,
+  pub fields: Vec<GroupField>,
+  /// Generates a `<Struct><Group>` view struct instead of a tuple.
+  pub view: bool,
+  pub getter: Option<(syn::Visibility, syn::Ident)>,
+  pub setter: Option<(syn::Visibility, syn::Ident, SetterStyle)>,
+  pub memo: Memo,
+  pub getter_attrs: Vec<syn::Attribute>,
+  pub setter_attrs: Vec<syn::Attribute>,
+}
+
+impl FieldGroup {
+  pub fn new(name: syn::Ident, struct_name: syn::Ident, generics: syn::Generics) -> Self {
+    Self {
+      name,
+      struct_name,
+      generics,
+      fields: Vec::new(),
+      view: false,
+      getter: None,
+      setter: None,
+      memo: Memo::default(),
+      getter_attrs: Vec::new(),
+      setter_attrs: Vec::new(),
+    }
+  }
+
+  fn view_name(&self) -> syn::Ident {
+    format_ident!(
+      "{}{}",
+      self.struct_name,
+      self.name.to_string().to_upper_camel_case()
+    )
+  }
+
+  fn view_generics(&self) -> syn::Generics {
+    let mut generics = self.generics.clone();
+    generics.params.insert(0, syn::parse_quote!('__view));
+    generics
+  }
+
+  /// Generates the view struct, which must be placed outside of the impl block.
+  pub fn to_view(&self) -> Option<proc_macro2::TokenStream> {
+    let (vis, _) = self.getter.as_ref().filter(|_| self.view)?;
+    let view_name = self.view_name();
+    let struct_name = &self.struct_name;
+    let (_, ty_generics, _) = self.generics.split_for_impl();
+    let view_generics = self.view_generics();
+    let (impl_generics, _, where_clause) = view_generics.split_for_impl();
+    let fields = self.fields.iter().map(|f| {
+      let field_name = &f.field_name;
+      let field_ty = &f.field_ty;
+      quote!(#vis #field_name: &'__view #field_ty)
+    });
+    let doc = format!("A view of the `{}` fields of [`{struct_name}`].", self.name);
+
+    Some(quote! {
+      #[doc = #doc]
+      #vis struct #view_name #impl_generics #where_clause {
+        #(#fields,)*
+        #[doc(hidden)]
+        __view: ::core::marker::PhantomData<&'__view #struct_name #ty_generics>,
+      }
+    })
+  }
+}
+
+impl ToTokens for FieldGroup {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let field_names = self
+      .fields
+      .iter()
+      .map(|f| &f.field_name)
+      .collect::<Vec<_>>();
+    let field_tys = self.fields.iter().map(|f| &f.field_ty).collect::<Vec<_>>();
+
+    if let Some((vis, fn_name)) = &self.getter {
+      let attrs = &self.getter_attrs;
+      tokens.extend(if self.view {
+        let view_name = self.view_name();
+        let view_generics = self.view_generics();
+        let (_, ty_generics, _) = view_generics.split_for_impl();
+        quote! {
+          #(#attrs)*
+          #vis fn #fn_name<'__view>(&'__view self) -> #view_name #ty_generics {
+            #view_name {
+              #(#field_names: &self.#field_names,)*
+              __view: ::core::marker::PhantomData,
+            }
+          }
+        }
+      } else {
+        quote! {
+          #(#attrs)*
+          #vis fn #fn_name(&self) -> (#(&#field_tys,)*) {
+            (#(&self.#field_names,)*)
+          }
+        }
+      });
+    }
+
+    if let Some((vis, fn_name, style)) = &self.setter {
+      let attrs = &self.setter_attrs;
+      let assign = self.fields.iter().map(GroupField::assign);
+      let memo = &self.memo;
+      tokens.extend(match style {
+        SetterStyle::Ref | SetterStyle::Cow => quote! {
+          #(#attrs)*
+          #vis fn #fn_name(&mut self, #(#field_names: #field_tys),*) {
+            #(#assign)*
+            #memo
+          }
+        },
+        _ => quote! {
+          #(#attrs)*
+          #vis fn #fn_name(mut self, #(#field_names: #field_tys),*) -> Self {
+            #(#assign)*
+            #memo
+            self
+          }
+        },
+      });
+    }
+  }
+}
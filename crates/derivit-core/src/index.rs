@@ -0,0 +1,180 @@
+use super::{computed::Memo, tracker::DirtyBit};
+use heck::ToShoutySnakeCase;
+use quote::{format_ident, quote, ToTokens};
+
+/// The indexed accessors of an array or a slice field.
+pub struct Index {
+  pub field_name: syn::Ident,
+  pub elem: syn::Type,
+  /// The length of the array, `None` for the slices.
+  pub len: Option<syn::Expr>,
+  pub mutable: bool,
+  pub getter: Option<(syn::Visibility, syn::Ident)>,
+  pub setter: Option<(syn::Visibility, syn::Ident)>,
+  /// The visibility of the `*_at_mut` getter.
+  pub mut_vis: syn::Visibility,
+  pub error: syn::Ident,
+  pub track: Option<DirtyBit>,
+  pub memo: Memo,
+  pub getter_attrs: Vec<syn::Attribute>,
+  pub setter_attrs: Vec<syn::Attribute>,
+}
+
+impl Index {
+  /// Accepts `[T; N]`, `Box<[T]>`, `&[T]` and `&mut [T]`.
+  pub fn from_type(field_name: &syn::Ident, ty: &syn::Type, error: syn::Ident) -> Option<Self> {
+    let (elem, len, mutable) = match ty {
+      syn::Type::Array(arr) => ((*arr.elem).clone(), Some(arr.len.clone()), true),
+      syn::Type::Reference(r) => match &*r.elem {
+        syn::Type::Slice(slice) => ((*slice.elem).clone(), None, r.mutability.is_some()),
+        _ => return None,
+      },
+      syn::Type::Path(path) => {
+        let segment = path.path.segments.last()?;
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+          return None;
+        };
+        match args.args.first() {
+          Some(syn::GenericArgument::Type(syn::Type::Slice(slice)))
+            if segment.ident == "Box" && args.args.len() == 1 =>
+          {
+            ((*slice.elem).clone(), None, true)
+          }
+          _ => return None,
+        }
+      }
+      _ => return None,
+    };
+    Some(Self {
+      field_name: field_name.clone(),
+      elem,
+      len,
+      mutable,
+      getter: None,
+      setter: None,
+      mut_vis: syn::Visibility::Inherited,
+      error,
+      track: None,
+      memo: Memo::default(),
+      getter_attrs: Vec::new(),
+      setter_attrs: Vec::new(),
+    })
+  }
+
+  fn at_fn(fn_name: &syn::Ident) -> syn::Ident {
+    format_ident!("{}_at", fn_name)
+  }
+
+  fn at_mut_fn(fn_name: &syn::Ident) -> syn::Ident {
+    format_ident!("{}_at_mut", fn_name)
+  }
+
+  pub fn fn_names(&self) -> Vec<syn::Ident> {
+    let mut names = Vec::new();
+    if let Some((_, fn_name)) = &self.getter {
+      names.push(Self::at_fn(fn_name));
+      if self.mutable {
+        names.push(Self::at_mut_fn(fn_name));
+      }
+    }
+    if let Some((_, fn_name)) = self.setter.as_ref().filter(|_| self.mutable) {
+      names.push(Self::at_fn(fn_name));
+    }
+    names
+  }
+
+  /// Returns whether the setter is generated, which needs the error type.
+  pub fn has_setter(&self) -> bool {
+    self.mutable && self.setter.is_some()
+  }
+}
+
+impl ToTokens for Index {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let field_name = &self.field_name;
+    let elem = &self.elem;
+    let mark = self.track.as_ref().map(DirtyBit::mark);
+    let memo = &self.memo;
+
+    if let Some(len) = &self.len {
+      let (vis, _) = self.getter.as_ref().or(self.setter.as_ref()).unwrap();
+      let len_const = format_ident!("{}_LEN", field_name.to_string().to_shouty_snake_case());
+      let doc = format!("The length of the `{field_name}` array.");
+      tokens.extend(quote! {
+        #[doc = #doc]
+        #vis const #len_const: usize = #len;
+      });
+    }
+
+    if let Some((vis, fn_name)) = &self.getter {
+      let attrs = &self.getter_attrs;
+      let at_fn = Self::at_fn(fn_name);
+      tokens.extend(quote! {
+        #(#attrs)*
+        #vis fn #at_fn(&self, i: usize) -> ::core::option::Option<&#elem> {
+          self.#field_name.get(i)
+        }
+      });
+      if self.mutable {
+        let at_mut_fn = Self::at_mut_fn(fn_name);
+        let mut_vis = &self.mut_vis;
+        tokens.extend(quote! {
+          #(#attrs)*
+          #mut_vis fn #at_mut_fn(&mut self, i: usize) -> ::core::option::Option<&mut #elem> {
+            #mark
+            #memo
+            self.#field_name.get_mut(i)
+          }
+        });
+      }
+    }
+
+    if let Some((vis, fn_name)) = self.setter.as_ref().filter(|_| self.mutable) {
+      let attrs = &self.setter_attrs;
+      let error = &self.error;
+      let at_fn = Self::at_fn(fn_name);
+      tokens.extend(quote! {
+        #(#attrs)*
+        #vis fn #at_fn(&mut self, i: usize, val: #elem) -> ::core::result::Result<(), #error> {
+          let len = self.#field_name.len();
+          match self.#field_name.get_mut(i) {
+            ::core::option::Option::Some(slot) => {
+              *slot = val;
+              #mark
+              #memo
+              ::core::result::Result::Ok(())
+            }
+            ::core::option::Option::None => {
+              ::core::result::Result::Err(#error { index: i, len })
+            }
+          }
+        }
+      });
+    }
+  }
+}
+
+/// Generates the error returned by the indexed setters when the index is out of bounds.
+pub fn index_error(vis: &syn::Visibility, error: &syn::Ident) -> proc_macro2::TokenStream {
+  quote! {
+    /// The error returned when the index is out of bounds.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #vis struct #error {
+      pub index: usize,
+      pub len: usize,
+    }
+
+    impl ::core::fmt::Display for #error {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(
+          f,
+          "index {} is out of bounds of length {}",
+          self.index,
+          self.len
+        )
+      }
+    }
+
+    impl ::core::error::Error for #error {}
+  }
+}
@@ -1,10 +1,22 @@
-#![allow(clippy::wrong_self_convention)]
+#![allow(clippy::wrong_self_convention, clippy::manual_unwrap_or_default)]
 
 use std::path::PathBuf;
 
+pub mod bits;
+pub mod collection;
+pub mod computed;
+pub mod endian;
 pub mod getter;
+pub mod group;
+pub mod index;
+pub mod lock;
+pub mod multi;
+pub mod packed;
 pub mod parser;
+pub mod pin;
+pub mod redact;
 pub mod setter;
+pub mod tracker;
 
 #[derive(Default, Clone)]
 pub struct FnGenerics {
@@ -27,6 +39,137 @@ impl darling::FromMeta for FnGenerics {
   }
 }
 
+#[derive(Default, Clone)]
+pub struct FnWhereClause {
+  pub where_clause: Option<syn::WhereClause>,
+}
+
+impl darling::FromMeta for FnWhereClause {
+  fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+    if let syn::Lit::Str(ref s) = value {
+      if s.value().is_empty() {
+        Ok(Self { where_clause: None })
+      } else {
+        let tt = format!("where {}", s.value());
+        let where_clause = syn::parse_str::<syn::WhereClause>(&tt)?;
+        Ok(Self {
+          where_clause: Some(where_clause),
+        })
+      }
+    } else {
+      Err(darling::Error::custom("expected str literal").with_span(value))
+    }
+  }
+}
+
+/// A comma separated list of identifiers, e.g. `"new, clone"`.
+#[derive(Default, Clone)]
+pub struct IdentList {
+  pub idents: Vec<syn::Ident>,
+}
+
+impl IdentList {
+  pub fn contains(&self, ident: &syn::Ident) -> bool {
+    self.idents.contains(ident)
+  }
+}
+
+impl darling::FromMeta for IdentList {
+  fn from_string(value: &str) -> darling::Result<Self> {
+    value
+      .split(',')
+      .map(str::trim)
+      .filter(|s| !s.is_empty())
+      .map(|s| syn::parse_str(s).map_err(Into::into))
+      .collect::<darling::Result<_>>()
+      .map(|idents| Self { idents })
+  }
+
+  // point the idents at the string literal, so the errors about them are spanned
+  fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+    match value {
+      syn::Lit::Str(lit) => Self::from_string(&lit.value()).map(|mut list| {
+        list
+          .idents
+          .iter_mut()
+          .for_each(|ident| ident.set_span(lit.span()));
+        list
+      }),
+      _ => Err(darling::Error::unexpected_lit_type(value)),
+    }
+    .map_err(|e| e.with_span(value))
+  }
+}
+
+/// The attributes attached to the generated accessors, e.g. `attrs(track_caller, doc(hidden))`.
+#[derive(Default, Clone)]
+pub struct Attributes {
+  pub attrs: Vec<syn::Attribute>,
+}
+
+impl darling::FromMeta for Attributes {
+  fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+    items
+      .iter()
+      .map(|item| match item {
+        syn::NestedMeta::Meta(meta) => Ok(syn::parse_quote!(#[#meta])),
+        syn::NestedMeta::Lit(lit) => {
+          Err(darling::Error::unsupported_format("literal").with_span(lit))
+        }
+      })
+      .collect::<darling::Result<_>>()
+      .map(|attrs| Self { attrs })
+  }
+}
+
+#[derive(Default, Clone, Copy)]
+pub enum Inline {
+  #[default]
+  Inline,
+  Always,
+  Never,
+  None,
+}
+
+impl darling::FromMeta for Inline {
+  fn from_string(value: &str) -> darling::Result<Self> {
+    match value {
+      "always" => Ok(Self::Always),
+      "never" => Ok(Self::Never),
+      "none" => Ok(Self::None),
+      other => Err(darling::Error::unknown_value(other)),
+    }
+  }
+}
+
+impl Inline {
+  pub fn to_attr(self) -> Option<syn::Attribute> {
+    match self {
+      Self::Inline => Some(syn::parse_quote!(#[inline])),
+      Self::Always => Some(syn::parse_quote!(#[inline(always)])),
+      Self::Never => Some(syn::parse_quote!(#[inline(never)])),
+      Self::None => None,
+    }
+  }
+}
+
+#[derive(Clone)]
+pub struct Readonly {
+  pub vis: syn::Visibility,
+}
+
+impl darling::FromMeta for Readonly {
+  fn from_word() -> darling::Result<Self> {
+    Ok(Self {
+      vis: syn::Visibility::Inherited,
+    })
+  }
+
+  fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+    syn::Visibility::from_value(value).map(|vis| Self { vis })
+  }
+}
+
 #[derive(Default, Clone)]
 pub enum DebugOutput {
   #[default]
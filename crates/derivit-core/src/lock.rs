@@ -0,0 +1,167 @@
+use darling::FromMeta;
+use quote::{format_ident, quote};
+
+/// How the lock getters handle a poisoned lock.
+#[derive(Default, Clone, Copy)]
+pub enum Poison {
+  #[default]
+  Panic,
+  IntoInner,
+  Result,
+}
+
+impl FromMeta for Poison {
+  fn from_word() -> darling::Result<Self> {
+    Ok(Self::Panic)
+  }
+
+  fn from_string(value: &str) -> darling::Result<Self> {
+    match value {
+      "panic" => Ok(Self::Panic),
+      "into_inner" => Ok(Self::IntoInner),
+      "result" => Ok(Self::Result),
+      other => Err(darling::Error::unknown_value(other)),
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+enum LockKind {
+  Mutex,
+  RwLock,
+}
+
+#[derive(Clone)]
+pub struct Lock {
+  kind: LockKind,
+  ty: syn::Type,
+  poison: Poison,
+}
+
+impl Lock {
+  /// Accepts `Mutex<T>` and `RwLock<T>`, either unqualified or as `std::sync::*`,
+  /// since the getters return the guards of `std`.
+  pub fn from_type(ty: &syn::Type, poison: Poison) -> darling::Result<Self> {
+    let expected =
+      || darling::Error::custom("`lock` getter expects a `Mutex` or `RwLock` field").with_span(ty);
+    let syn::Type::Path(path) = ty else {
+      return Err(expected());
+    };
+    let Some(segment) = path.path.segments.last() else {
+      return Err(expected());
+    };
+    let kind = match segment.ident.to_string().as_str() {
+      "Mutex" => LockKind::Mutex,
+      "RwLock" => LockKind::RwLock,
+      _ => return Err(expected()),
+    };
+    let prefix = path
+      .path
+      .segments
+      .iter()
+      .rev()
+      .skip(1)
+      .map(|s| s.ident.to_string())
+      .collect::<Vec<_>>();
+    if path.qself.is_some() || !(prefix.is_empty() || prefix == ["sync", "std"]) {
+      return Err(
+        darling::Error::custom(format!(
+          "`lock` getter expects `std::sync::{}`, other locks are not supported",
+          segment.ident
+        ))
+        .with_span(&path.path),
+      );
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+      return Err(expected());
+    };
+    args
+      .args
+      .iter()
+      .find_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+      })
+      .map(|ty| Self { kind, ty, poison })
+      .ok_or_else(expected)
+  }
+
+  pub fn fn_names(&self, fn_name: &syn::Ident) -> Vec<syn::Ident> {
+    match self.kind {
+      LockKind::Mutex => vec![fn_name.clone()],
+      LockKind::RwLock => vec![
+        format_ident!("read_{}", fn_name),
+        format_ident!("write_{}", fn_name),
+      ],
+    }
+  }
+
+  fn guard(
+    &self,
+    field_name: &syn::Ident,
+    method: &str,
+    guard: proc_macro2::TokenStream,
+  ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let method = format_ident!("{}", method);
+    let lock = quote!(self.#field_name.#method());
+    match self.poison {
+      Poison::Panic => {
+        let msg = format!("the lock of field `{field_name}` is poisoned");
+        (guard, quote!(#lock.expect(#msg)))
+      }
+      Poison::IntoInner => (
+        guard,
+        quote!(#lock.unwrap_or_else(::std::sync::PoisonError::into_inner)),
+      ),
+      Poison::Result => (quote!(::std::sync::LockResult<#guard>), lock),
+    }
+  }
+
+  pub fn to_getters(
+    &self,
+    vis: &syn::Visibility,
+    mut_vis: &syn::Visibility,
+    fn_name: &syn::Ident,
+    field_name: &syn::Ident,
+    attrs: &[syn::Attribute],
+    where_clause: Option<&syn::WhereClause>,
+  ) -> proc_macro2::TokenStream {
+    let ty = &self.ty;
+    let getter = |vis: &syn::Visibility,
+                  fn_name: syn::Ident,
+                  method: &str,
+                  guard: proc_macro2::TokenStream| {
+      let (output, body) = self.guard(field_name, method, guard);
+      quote! {
+        #(#attrs)*
+        #vis fn #fn_name(&self) -> #output #where_clause {
+          #body
+        }
+      }
+    };
+
+    match self.kind {
+      LockKind::Mutex => getter(
+        vis,
+        fn_name.clone(),
+        "lock",
+        quote!(::std::sync::MutexGuard<'_, #ty>),
+      ),
+      LockKind::RwLock => {
+        let read = getter(
+          vis,
+          format_ident!("read_{}", fn_name),
+          "read",
+          quote!(::std::sync::RwLockReadGuard<'_, #ty>),
+        );
+        let write = getter(
+          mut_vis,
+          format_ident!("write_{}", fn_name),
+          "write",
+          quote!(::std::sync::RwLockWriteGuard<'_, #ty>),
+        );
+        quote!(#read #write)
+      }
+    }
+  }
+}
@@ -0,0 +1,100 @@
+use super::{computed::Memo, group::GroupField, setter::SetterStyle, tracker::DirtyBit, IdentList};
+use darling::FromMeta;
+use quote::{quote, ToTokens};
+
+#[derive(FromMeta, Clone)]
+pub struct MultiOptions {
+  pub name: syn::Ident,
+  pub fields: IdentList,
+  pub vis: Option<syn::Visibility>,
+}
+
+/// The accessors of several fields at once, in the order of the `fields` list.
+pub struct MultiAccessor {
+  pub name: syn::Ident,
+  pub fields: Vec<GroupField>,
+  pub getter: Option<(syn::Visibility, syn::Ident)>,
+  /// The `*_mut` getter borrowing all the fields mutably, which is only generated with the setter.
+  pub getter_mut: Option<(syn::Visibility, syn::Ident)>,
+  pub setter: Option<(syn::Visibility, syn::Ident, SetterStyle)>,
+  pub memo: Memo,
+  pub getter_attrs: Vec<syn::Attribute>,
+  pub setter_attrs: Vec<syn::Attribute>,
+}
+
+impl MultiAccessor {
+  pub fn fn_names(&self) -> Vec<syn::Ident> {
+    self
+      .getter
+      .iter()
+      .chain(&self.getter_mut)
+      .map(|(_, ident)| ident.clone())
+      .chain(self.setter.iter().map(|(_, ident, _)| ident.clone()))
+      .collect()
+  }
+}
+
+impl ToTokens for MultiAccessor {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let field_names = self
+      .fields
+      .iter()
+      .map(|f| &f.field_name)
+      .collect::<Vec<_>>();
+    let field_tys = self.fields.iter().map(|f| &f.field_ty).collect::<Vec<_>>();
+
+    if let Some((vis, fn_name)) = &self.getter {
+      let attrs = &self.getter_attrs;
+      tokens.extend(quote! {
+        #(#attrs)*
+        #vis fn #fn_name(&self) -> (#(&#field_tys,)*) {
+          (#(&self.#field_names,)*)
+        }
+      });
+    }
+
+    if let Some((vis, fn_name)) = &self.getter_mut {
+      let attrs = &self.setter_attrs;
+      let memo = &self.memo;
+      let marks = self
+        .fields
+        .iter()
+        .filter_map(|f| f.track.as_ref().map(DirtyBit::mark));
+      tokens.extend(quote! {
+        #(#attrs)*
+        #vis fn #fn_name(&mut self) -> (#(&mut #field_tys,)*) {
+          #(#marks)*
+          #memo
+          (#(&mut self.#field_names,)*)
+        }
+      });
+    }
+
+    if let Some((vis, fn_name, style)) = &self.setter {
+      let attrs = &self.setter_attrs;
+      let assign = self.fields.iter().map(GroupField::assign);
+      let memo = &self.memo;
+      // destructure in the body, the patterns are not allowed in the trait methods of `remote!`
+      let destructure = quote!(let (#(#field_names,)*) = val;);
+      tokens.extend(match style {
+        SetterStyle::Ref | SetterStyle::Cow => quote! {
+          #(#attrs)*
+          #vis fn #fn_name(&mut self, val: (#(#field_tys,)*)) {
+            #destructure
+            #(#assign)*
+            #memo
+          }
+        },
+        _ => quote! {
+          #(#attrs)*
+          #vis fn #fn_name(mut self, val: (#(#field_tys,)*)) -> Self {
+            #destructure
+            #(#assign)*
+            #memo
+            self
+          }
+        },
+      });
+    }
+  }
+}
@@ -0,0 +1,26 @@
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+
+pub fn is_packed(attrs: &[syn::Attribute]) -> bool {
+  attrs
+    .iter()
+    .filter(|attr| attr.path.is_ident("repr"))
+    .any(|attr| match attr.parse_meta() {
+      Ok(syn::Meta::List(list)) => list
+        .nested
+        .iter()
+        .any(|meta| matches!(meta, syn::NestedMeta::Meta(meta) if meta.path().is_ident("packed"))),
+      _ => false,
+    })
+}
+
+/// References to the fields of a `#[repr(packed)]` struct are not allowed,
+/// so the accessors copy the fields, which requires the field type to be `Copy`.
+pub fn assert_copy(ty: &syn::Type) -> proc_macro2::TokenStream {
+  quote_spanned! {ty.span()=>
+    let _: fn() = || {
+      fn field_of_packed_struct_must_be_copy<T: ::core::marker::Copy>() {}
+      field_of_packed_struct_must_be_copy::<#ty>();
+    };
+  }
+}
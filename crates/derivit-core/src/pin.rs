@@ -0,0 +1,105 @@
+use super::{computed::Memo, tracker::DirtyBit};
+use quote::{format_ident, quote, ToTokens};
+
+pub struct PinProjection {
+  pub vis: syn::Visibility,
+  pub fn_name: syn::Ident,
+  pub field_name: syn::Ident,
+  pub field_ty: syn::Type,
+  pub pinned: bool,
+  pub track: Option<DirtyBit>,
+  pub memo: Memo,
+  pub attrs: Vec<syn::Attribute>,
+}
+
+impl ToTokens for PinProjection {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let vis = &self.vis;
+    let fn_name = &self.fn_name;
+    let field_name = &self.field_name;
+    let field_ty = &self.field_ty;
+    let attrs = &self.attrs;
+    // the dirty bits and the cells of the memoised values are not structurally pinned
+    let mark = self.track.as_ref().map(|t| t.mark_of(&quote!(this)));
+    let memo = self.memo.reset(&quote!(this));
+
+    tokens.extend(if self.pinned {
+      quote! {
+        #(#attrs)*
+        #vis fn #fn_name(self: ::core::pin::Pin<&mut Self>) -> ::core::pin::Pin<&mut #field_ty> {
+          // SAFETY: the field is structurally pinned, the generated `Unpin` impl
+          // and the `Drop` guard make sure it is never moved out of the pin.
+          unsafe {
+            self.map_unchecked_mut(|this| {
+              #mark
+              #memo
+              &mut this.#field_name
+            })
+          }
+        }
+      }
+    } else {
+      quote! {
+        #(#attrs)*
+        #vis fn #fn_name(self: ::core::pin::Pin<&mut Self>) -> &mut #field_ty {
+          // SAFETY: the field is not structurally pinned.
+          let this = unsafe { self.get_unchecked_mut() };
+          #mark
+          #memo
+          &mut this.#field_name
+        }
+      }
+    });
+  }
+}
+
+/// Generates the guards which keep the pin projections sound:
+///
+/// - `Self` is `Unpin` only if all the pinned fields are `Unpin`, a conflicting
+///   `Unpin` impl written by the user is rejected.
+/// - `Self` must not implement `Drop`, because `Drop::drop` takes `&mut Self`,
+///   which could move the pinned fields.
+pub fn pin_guards<'a>(
+  name: &syn::Ident,
+  generics: &syn::Generics,
+  projections: impl Iterator<Item = &'a PinProjection>,
+) -> proc_macro2::TokenStream {
+  let pinned_fields = projections
+    .filter(|p| p.pinned)
+    .map(|p| {
+      let field_name = &p.field_name;
+      let field_ty = &p.field_ty;
+      quote!(#field_name: #field_ty)
+    })
+    .collect::<Vec<_>>();
+  let unpin_struct = format_ident!("__{}PinnedFields", name);
+  let drop_guard = format_ident!("__{}MustNotImplDrop", name);
+
+  let mut unpin_generics = generics.clone();
+  unpin_generics.params.insert(0, syn::parse_quote!('__pin));
+  let (unpin_impl_generics, unpin_ty_generics, _) = unpin_generics.split_for_impl();
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  let mut unpin_where = generics.clone();
+  unpin_where
+    .make_where_clause()
+    .predicates
+    .push(syn::parse_quote!(#unpin_struct #unpin_ty_generics: ::core::marker::Unpin));
+  let unpin_where = &unpin_where.where_clause;
+
+  quote! {
+    const _: () = {
+      #[allow(dead_code)]
+      struct #unpin_struct #unpin_impl_generics #where_clause {
+        __pin: ::core::marker::PhantomData<(&'__pin (), fn() -> #name #ty_generics)>,
+        #(#pinned_fields),*
+      }
+
+      impl #unpin_impl_generics ::core::marker::Unpin for #name #ty_generics #unpin_where {}
+
+      trait #drop_guard {}
+      #[allow(drop_bounds)]
+      impl<T: ::core::ops::Drop> #drop_guard for T {}
+      impl #impl_generics #drop_guard for #name #ty_generics #where_clause {}
+    };
+  }
+}
@@ -0,0 +1,64 @@
+use quote::{quote, ToTokens};
+
+pub struct DebugField {
+  pub name: syn::Ident,
+  pub sensitive: bool,
+  /// The forwarded `cfg` and `allow` attributes of the field.
+  pub attrs: Vec<syn::Attribute>,
+}
+
+/// The `Debug` impl which prints `<redacted>` instead of the values of the sensitive fields.
+pub struct DebugImpl {
+  pub struct_name: syn::Ident,
+  pub generics: syn::Generics,
+  /// The fields in the declaration order.
+  pub fields: Vec<DebugField>,
+  pub packed: bool,
+}
+
+impl ToTokens for DebugImpl {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let struct_name = &self.struct_name;
+    let name = struct_name.to_string();
+    // bound the type parameters by `Debug`, as `#[derive(Debug)]` does
+    let mut generics = self.generics.clone();
+    let bounds = self
+      .generics
+      .type_params()
+      .map(|param| {
+        let ident = &param.ident;
+        syn::parse_quote!(#ident: ::core::fmt::Debug)
+      })
+      .collect::<Vec<syn::WherePredicate>>();
+    generics.make_where_clause().predicates.extend(bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields = self.fields.iter().map(|field| {
+      let field_name = &field.name;
+      let attrs = &field.attrs;
+      let label = field_name.to_string();
+      let val = if field.sensitive {
+        quote!(&::core::format_args!("<redacted>"))
+      } else if self.packed {
+        // take a reference to a copy of the field for the packed struct
+        quote!(&{ self.#field_name })
+      } else {
+        quote!(&self.#field_name)
+      };
+      quote! {
+        #(#attrs)*
+        s.field(#label, #val);
+      }
+    });
+
+    tokens.extend(quote! {
+      impl #impl_generics ::core::fmt::Debug for #struct_name #ty_generics #where_clause {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          let mut s = f.debug_struct(#name);
+          #(#fields)*
+          s.finish()
+        }
+      }
+    });
+  }
+}
@@ -1,26 +1,50 @@
-use super::FnGenerics;
-use darling::FromMeta;
+use super::{
+  computed::Memo,
+  endian::{Endian, EndianConverter},
+  packed::assert_copy,
+  tracker::DirtyBit,
+  Attributes, FnGenerics, FnWhereClause, Inline,
+};
+use darling::{util::SpannedValue, FromMeta};
 use quote::{format_ident, quote, ToTokens};
 
 #[derive(Default, FromMeta)]
 pub struct FieldSetterOptions {
   pub rename: Option<syn::Ident>,
   pub style: Option<SetterStyle>,
-  #[darling(default, rename = "skip")]
-  pub ignore: bool,
+  #[darling(rename = "skip")]
+  pub ignore: Option<bool>,
   pub vis: Option<syn::Visibility>,
   #[darling(default)]
   pub bound: FnGenerics,
+  #[darling(default, rename = "where")]
+  pub where_clause: FnWhereClause,
+  #[darling(default)]
+  pub impl_where: FnWhereClause,
+  pub replace: Option<bool>,
+  pub on_change: Option<OnChange>,
+  pub endian: Option<SpannedValue<Endian>>,
+  pub ty: Option<syn::Type>,
+  #[darling(default)]
+  pub attrs: Attributes,
+  pub inline: Option<Inline>,
+  pub error: Option<syn::Type>,
+  pub map_err: Option<syn::Expr>,
 }
 
 #[derive(FromMeta)]
+#[darling(default)]
 pub struct StructSetterOptions {
   pub prefix: Option<syn::Ident>,
-  #[darling(default)]
   pub style: SetterStyle,
-  #[darling(default, rename = "skip")]
+  #[darling(rename = "skip")]
   pub ignore: bool,
   pub vis_all: Option<syn::Visibility>,
+  pub replace: bool,
+  pub on_change: Option<OnChange>,
+  pub attrs: Attributes,
+  pub inline: Inline,
+  pub error: Option<syn::Type>,
 }
 
 impl Default for StructSetterOptions {
@@ -30,6 +54,65 @@ impl Default for StructSetterOptions {
       style: SetterStyle::Move,
       ignore: false,
       vis_all: None,
+      replace: false,
+      on_change: None,
+      attrs: Attributes::default(),
+      inline: Inline::default(),
+      error: None,
+    }
+  }
+}
+
+#[derive(FromMeta)]
+struct OnChangeOptions {
+  #[darling(rename = "fn")]
+  func: syn::Path,
+  #[darling(default)]
+  partial_eq: bool,
+}
+
+#[derive(Clone)]
+pub struct OnChange {
+  pub func: syn::Path,
+  pub partial_eq: bool,
+}
+
+impl FromMeta for OnChange {
+  fn from_string(value: &str) -> darling::Result<Self> {
+    Ok(Self {
+      func: syn::parse_str(value)?,
+      partial_eq: false,
+    })
+  }
+
+  fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+    OnChangeOptions::from_list(items).map(|opts| Self {
+      func: opts.func,
+      partial_eq: opts.partial_eq,
+    })
+  }
+}
+
+impl OnChange {
+  pub(crate) fn notify(
+    &self,
+    field_name: &syn::Ident,
+    old: &proc_macro2::TokenStream,
+    val: &proc_macro2::TokenStream,
+  ) -> proc_macro2::TokenStream {
+    let func = &self.func;
+    let name = field_name.to_string();
+    let call = quote! {
+      #func(&self, #name, #old, &#val);
+    };
+    if self.partial_eq {
+      quote! {
+        if *#old != #val {
+          #call
+        }
+      }
+    } else {
+      call
     }
   }
 }
@@ -45,53 +128,132 @@ pub enum SetterStyle {
   Into,
   #[darling(rename = "try_into")]
   TryInto,
+  /// `&mut self` setters and `*_mut` accessors for the `Arc<T>` and `Rc<T>` fields,
+  /// which clone the shared value on write by `make_mut`.
+  #[darling(rename = "cow")]
+  Cow,
+}
+
+/// Returns `T` of the `Arc<T>` or `Rc<T>` type.
+pub fn cow_inner(ty: &syn::Type) -> Option<&syn::Type> {
+  let syn::Type::Path(path) = ty else {
+    return None;
+  };
+  let segment = path.path.segments.last()?;
+  if segment.ident != "Arc" && segment.ident != "Rc" {
+    return None;
+  }
+  let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+    return None;
+  };
+  match args.args.first() {
+    Some(syn::GenericArgument::Type(ty)) if args.args.len() == 1 => Some(ty),
+    _ => None,
+  }
 }
 
 impl SetterStyle {
   fn to_setter(
     &self,
-    fn_vis: &syn::Visibility,
-    bound: Option<&syn::Generics>,
-    field_name: &syn::Ident,
-    field_ty: &syn::Type,
-    fn_name: &syn::Ident,
+    setter: &FieldSetter,
+    assign: proc_macro2::TokenStream,
   ) -> proc_macro2::TokenStream {
+    let FieldSetter {
+      vis: fn_vis,
+      bound,
+      where_clause,
+      field_ty,
+      fn_name,
+      attrs,
+      endian,
+      ..
+    } = setter;
+    let bound = bound.as_ref();
+    let (input_ty, assign) = match endian {
+      Some(endian) => {
+        let to_bytes = endian.to_bytes();
+        (
+          &endian.ty,
+          quote! {
+            let val: #field_ty = #to_bytes(val);
+            #assign
+          },
+        )
+      }
+      None => match (self, cow_inner(field_ty)) {
+        (Self::Cow, Some(inner)) => (
+          inner,
+          quote! {
+            let val: #field_ty = <#field_ty>::new(val);
+            #assign
+          },
+        ),
+        _ => (field_ty, assign),
+      },
+    };
     match self {
-      Self::Ref => quote! {
-        #[inline]
-        #fn_vis fn #fn_name #bound (&mut self, val: #field_ty) {
-          self.#field_name = val;
+      Self::Ref | Self::Cow => quote! {
+        #(#attrs)*
+        #fn_vis fn #fn_name #bound (&mut self, val: #input_ty) #where_clause {
+          #assign
         }
 
       },
       Self::Move => quote! {
-        #[inline]
-        #fn_vis fn #fn_name #bound (mut self, val: #field_ty) -> Self {
-          self.#field_name = val;
+        #(#attrs)*
+        #fn_vis fn #fn_name #bound (mut self, val: #input_ty) -> Self #where_clause {
+          #assign
           self
         }
 
       },
       Self::Into => quote! {
-        #[inline]
-        #fn_vis fn #fn_name #bound (mut self, val: impl core::convert::Into<#field_ty>) -> Self {
-          self.#field_name = ::core::convert::Into::into(val);
+        #(#attrs)*
+        #fn_vis fn #fn_name #bound (mut self, val: impl ::core::convert::Into<#input_ty>) -> Self #where_clause {
+          let val: #input_ty = ::core::convert::Into::into(val);
+          #assign
           self
         }
 
       },
+      Self::TryInto if setter.error.is_some() => {
+        let TryIntoError { ty, map_err } = setter.error.as_ref().unwrap();
+        let (bound, input, map_err) = match map_err {
+          // the error of `TryInto` is a named param, so that `map_err` can rely on its bounds given
+          // by `where`, the name does not shadow the types used in `map_err`
+          Some(map_err) => {
+            let mut bound = bound.cloned().unwrap_or_default();
+            bound.params.push(syn::parse_quote!(__ViewitTryIntoError));
+            (
+              Some(bound),
+              quote!(impl ::core::convert::TryInto<#input_ty, Error = __ViewitTryIntoError>),
+              quote!(#map_err),
+            )
+          }
+          None => (
+            bound.cloned(),
+            quote!(impl ::core::convert::TryInto<#input_ty, Error = impl ::core::convert::Into<#ty>>),
+            quote!(::core::convert::Into::<#ty>::into),
+          ),
+        };
+        quote! {
+          #(#attrs)*
+          #fn_vis fn #fn_name #bound (mut self, val: #input) -> ::core::result::Result<Self, #ty> #where_clause {
+            let val: #input_ty = ::core::convert::TryInto::try_into(val).map_err(#map_err)?;
+            #assign
+            ::core::result::Result::Ok(self)
+          }
+
+        }
+      }
       Self::TryInto => {
-        let bound = bound.map(|tt| {
-          let bound = format!(
-            "{}, Error>",
-            tt.to_token_stream().to_string().trim_end_matches('>')
-          );
-          syn::parse_str::<syn::Generics>(&bound).unwrap()
-        });
+        let mut bound = bound.cloned().unwrap_or_default();
+        bound.params.push(syn::parse_quote!(Error));
         quote! {
-          #[inline]
-          #fn_vis fn #fn_name #bound (mut self, val: impl ::core::convert::TryInto<#field_ty, Error = Error>) -> ::core::result::Result<Self, Error> {
-            self.#field_name = ::core::convert::TryInto::try_into(val)?;
+          #(#attrs)*
+          #fn_vis fn #fn_name #bound (mut self, val: impl ::core::convert::TryInto<#input_ty, Error = Error>) -> ::core::result::Result<Self, Error> #where_clause {
+            let val: #input_ty = ::core::convert::TryInto::try_into(val)?;
+            #assign
             ::core::result::Result::Ok(self)
           }
 
@@ -101,23 +263,113 @@ impl SetterStyle {
   }
 }
 
+/// The error type returned by the `try_into` setter instead of the error of `TryInto`.
+pub struct TryIntoError {
+  pub ty: syn::Type,
+  /// The fn or closure which maps the error of `TryInto` to `ty`.
+  pub map_err: Option<syn::Expr>,
+}
+
 pub struct FieldSetter {
   pub vis: syn::Visibility,
   pub bound: Option<syn::Generics>,
+  pub where_clause: Option<syn::WhereClause>,
+  pub impl_where: Option<syn::WhereClause>,
   pub field_name: syn::Ident,
   pub field_ty: syn::Type,
   pub fn_name: syn::Ident,
   pub style: SetterStyle,
+  pub replace: bool,
+  pub track: Option<DirtyBit>,
+  pub memo: Memo,
+  pub on_change: Option<OnChange>,
+  pub endian: Option<EndianConverter>,
+  pub error: Option<TryIntoError>,
+  pub packed: bool,
+  pub attrs: Vec<syn::Attribute>,
+}
+
+impl FieldSetter {
+  fn replace_fn(&self) -> syn::Ident {
+    format_ident!("replace_{}", self.field_name)
+  }
+
+  fn cow_mut_fn(&self) -> Option<syn::Ident> {
+    matches!(self.style, SetterStyle::Cow).then(|| format_ident!("{}_mut", self.field_name))
+  }
+
+  pub fn fn_names(&self) -> Vec<syn::Ident> {
+    let mut names = vec![self.fn_name.clone()];
+    if self.replace {
+      names.push(self.replace_fn());
+    }
+    names.extend(self.cow_mut_fn());
+    names
+  }
 }
 
 impl ToTokens for FieldSetter {
   fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
     let fn_vis = &self.vis;
-    let bound = self.bound.as_ref();
     let field_name = &self.field_name;
     let field_ty = &self.field_ty;
-    let fn_name = &self.fn_name;
-    let style = &self.style;
-    tokens.extend(style.to_setter(fn_vis, bound, field_name, field_ty, fn_name));
+    let attrs = &self.attrs;
+    let val = quote!(val);
+    let mark = self.track.as_ref().map(DirtyBit::mark);
+    let memo = &self.memo;
+    let assert_copy = self.packed.then(|| assert_copy(field_ty));
+    // take a reference to a copy of the field for the packed struct
+    let old = if self.packed {
+      quote!(&{ self.#field_name })
+    } else {
+      quote!(&self.#field_name)
+    };
+    let notify = self
+      .on_change
+      .as_ref()
+      .map(|on_change| on_change.notify(field_name, &old, &val));
+    let assign = quote! {
+      #assert_copy
+      #notify
+      self.#field_name = #val;
+      #mark
+      #memo
+    };
+    tokens.extend(self.style.to_setter(self, assign));
+
+    if self.replace {
+      let replace_fn = self.replace_fn();
+      let replace = if self.packed {
+        quote! {
+          let old = self.#field_name;
+          self.#field_name = val;
+          old
+        }
+      } else {
+        quote!(::core::mem::replace(&mut self.#field_name, val))
+      };
+      tokens.extend(quote! {
+        #(#attrs)*
+        #fn_vis fn #replace_fn(&mut self, val: #field_ty) -> #field_ty {
+          #assert_copy
+          #notify
+          #mark
+          #memo
+          #replace
+        }
+      });
+    }
+
+    if let (Some(mut_fn), Some(inner)) = (self.cow_mut_fn(), cow_inner(field_ty)) {
+      let where_clause = &self.where_clause;
+      tokens.extend(quote! {
+        #(#attrs)*
+        #fn_vis fn #mut_fn(&mut self) -> &mut #inner #where_clause {
+          #mark
+          #memo
+          <#field_ty>::make_mut(&mut self.#field_name)
+        }
+      });
+    }
   }
 }
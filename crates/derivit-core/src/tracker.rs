@@ -0,0 +1,203 @@
+use quote::{format_ident, quote, ToTokens};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+pub struct ChangeTracker {
+  pub vis: syn::Visibility,
+  pub field_name: syn::Ident,
+  /// The newtype of the bitset, whose comparisons and hashing ignore the bits.
+  pub ty: syn::Ident,
+  pub fields: Vec<syn::Ident>,
+  /// The attributes of `dirty_fields` and `is_dirty_*`.
+  pub getter_attrs: Vec<syn::Attribute>,
+  /// The attributes of `clear_dirty`.
+  pub setter_attrs: Vec<syn::Attribute>,
+}
+
+impl ChangeTracker {
+  pub fn new(vis: syn::Visibility, struct_name: &syn::Ident, fields: Vec<syn::Ident>) -> Self {
+    Self {
+      vis,
+      field_name: format_ident!("__viewit_dirty"),
+      ty: format_ident!("{}DirtyBits", struct_name),
+      fields,
+      getter_attrs: Vec::new(),
+      setter_attrs: Vec::new(),
+    }
+  }
+
+  fn words(&self) -> usize {
+    self.fields.len().div_ceil(WORD_BITS)
+  }
+
+  pub fn dirty_bit(&self, index: usize) -> DirtyBit {
+    DirtyBit {
+      tracker: self.field_name.clone(),
+      index,
+    }
+  }
+
+  pub fn fn_names(&self) -> Vec<syn::Ident> {
+    let mut names = vec![format_ident!("dirty_fields"), format_ident!("clear_dirty")];
+    names.extend(self.fields.iter().map(|f| format_ident!("is_dirty_{}", f)));
+    names
+  }
+
+  /// The bitset field, which is skipped by serde when the struct derives
+  /// `Serialize` or `Deserialize` in `struct_attrs`.
+  pub fn to_field(&self, struct_attrs: &[syn::Attribute]) -> syn::Field {
+    let field_name = &self.field_name;
+    let ty = &self.ty;
+    let mut attrs = vec![syn::parse_quote!(#[doc(hidden)])];
+    attrs.extend(serde_skip(struct_attrs));
+    syn::Field {
+      attrs,
+      vis: syn::Visibility::Inherited,
+      ident: Some(field_name.clone()),
+      colon_token: Some(Default::default()),
+      ty: syn::parse_quote!(#ty),
+    }
+  }
+
+  /// Generates the newtype of the bitset, which must be placed outside of the impl block.
+  pub fn to_type(&self) -> proc_macro2::TokenStream {
+    let ty = &self.ty;
+    let words = self.words();
+    quote! {
+      /// The dirty bits of the fields, which are always equal, so they do not
+      /// change the comparisons and hashing derived for the struct.
+      #[doc(hidden)]
+      #[derive(Debug, Clone, Copy)]
+      struct #ty([u64; #words]);
+
+      impl ::core::default::Default for #ty {
+        fn default() -> Self {
+          Self([0; #words])
+        }
+      }
+
+      impl ::core::cmp::PartialEq for #ty {
+        fn eq(&self, _: &Self) -> bool {
+          true
+        }
+      }
+
+      impl ::core::cmp::Eq for #ty {}
+
+      impl ::core::cmp::PartialOrd for #ty {
+        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+          ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+        }
+      }
+
+      impl ::core::cmp::Ord for #ty {
+        fn cmp(&self, _: &Self) -> ::core::cmp::Ordering {
+          ::core::cmp::Ordering::Equal
+        }
+      }
+
+      impl ::core::hash::Hash for #ty {
+        fn hash<H: ::core::hash::Hasher>(&self, _: &mut H) {}
+      }
+    }
+  }
+}
+
+fn is_serde_derive(meta: &syn::Meta) -> bool {
+  match meta {
+    syn::Meta::List(list) if list.path.is_ident("derive") => list.nested.iter().any(|derive| {
+      matches!(derive, syn::NestedMeta::Meta(derive) if derive
+        .path()
+        .segments
+        .last()
+        .is_some_and(|s| s.ident == "Serialize" || s.ident == "Deserialize"))
+    }),
+    _ => false,
+  }
+}
+
+/// `#[serde(skip)]`, or `#[cfg_attr(.., serde(skip))]` when the serde derives are behind a `cfg_attr`.
+pub(crate) fn serde_skip(attrs: &[syn::Attribute]) -> Option<syn::Attribute> {
+  attrs.iter().find_map(|attr| match attr.parse_meta().ok()? {
+    meta if is_serde_derive(&meta) => Some(syn::parse_quote!(#[serde(skip)])),
+    syn::Meta::List(list) if list.path.is_ident("cfg_attr") => {
+      let cond = list.nested.first()?;
+      list
+        .nested
+        .iter()
+        .skip(1)
+        .any(|meta| matches!(meta, syn::NestedMeta::Meta(meta) if is_serde_derive(meta)))
+        .then(|| syn::parse_quote!(#[cfg_attr(#cond, serde(skip))]))
+    }
+    _ => None,
+  })
+}
+
+impl ToTokens for ChangeTracker {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let vis = &self.vis;
+    let tracker = &self.field_name;
+    let getter_attrs = &self.getter_attrs;
+    let setter_attrs = &self.setter_attrs;
+    let names = self.fields.iter().map(|f| f.to_string());
+    let bits = (0..self.fields.len()).map(|i| self.dirty_bit(i).is_set());
+    let is_dirty_fns = self.fields.iter().enumerate().map(|(i, field_name)| {
+      let fn_name = format_ident!("is_dirty_{}", field_name);
+      let bit = self.dirty_bit(i).is_set();
+      quote! {
+        #(#getter_attrs)*
+        #vis fn #fn_name(&self) -> bool {
+          #bit
+        }
+      }
+    });
+
+    tokens.extend(quote! {
+      #(#getter_attrs)*
+      #vis fn dirty_fields(&self) -> impl ::core::iter::Iterator<Item = &'static str> {
+        [#(#names),*]
+          .into_iter()
+          .zip([#(#bits),*])
+          .filter_map(|(name, dirty)| dirty.then_some(name))
+      }
+
+      #(#is_dirty_fns)*
+
+      #(#setter_attrs)*
+      #vis fn clear_dirty(&mut self) {
+        self.#tracker = ::core::default::Default::default();
+      }
+    });
+  }
+}
+
+#[derive(Clone)]
+pub struct DirtyBit {
+  pub tracker: syn::Ident,
+  pub index: usize,
+}
+
+impl DirtyBit {
+  pub fn mark(&self) -> proc_macro2::TokenStream {
+    self.mark_of(&quote!(self))
+  }
+
+  /// Marks the bit of `this`, which is `self` except in the pin projections.
+  pub fn mark_of(&self, this: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let tracker = &self.tracker;
+    let word = self.index / WORD_BITS;
+    let mask = 1u64 << (self.index % WORD_BITS);
+    quote! {
+      #this.#tracker.0[#word] |= #mask;
+    }
+  }
+
+  pub fn is_set(&self) -> proc_macro2::TokenStream {
+    let tracker = &self.tracker;
+    let word = self.index / WORD_BITS;
+    let mask = 1u64 << (self.index % WORD_BITS);
+    quote! {
+      (self.#tracker.0[#word] & #mask != 0)
+    }
+  }
+}
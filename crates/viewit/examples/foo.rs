@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use viewit::viewit;
 
 struct FromString {
@@ -22,19 +24,73 @@ fn vec_to_string(src: &[u8]) -> String {
   setters(
     // change the prefix for all setters
     prefix = "with",
-    // change the setters fn style, available values here are ref, into, tryinto or move
+    // change the setters fn style, available values here are ref, into, tryinto, move or cow,
+    // cow generates `&mut self` setters and `*_mut` accessors by `make_mut` for the `Arc` and `Rc` fields
     style = "ref",
     // if you do not want to generate getters, you can use skip
     // skip, 
+    // generate `replace_*` fns, which replace the field and return the old value
+    // replace,
+    // call `hook(&self, "field_name", &old, &new)` from every generated setter,
+    // use `on_change(fn = "Self::notify", partial_eq)` to skip the hook when the value is unchanged
+    // the `bits` setters pass the whole field, the `cow` setter and the mutable `index`
+    // accessors write in place, so they do not support the hook
+    // on_change = "Self::notify",
+    // make the `try_into` setters return this error type, the error of `TryInto` must implement
+    // `Into<ConfigError>`, or set `setter(map_err = "|e| ConfigError::field(\"f2\", e)")` on the field to map it,
+    // `map_err` adds the `__ViewitTryIntoError` generic param for the error of `TryInto`, since
+    // a generic `map_err` needs its bounds, e.g. `setter(where = "__ViewitTryIntoError: Display")`
+    // error = "ConfigError",
   ),
   getters(
     // change the prefix for all getters
     prefix = "get",
     // change the getters fn style, available values here are ref and move
+    // (the `&'a T` and `Option<&'a T>` fields are always returned by copy, so the value
+    // keeps the `'a` lifetime instead of being borrowed from `self` as `&&'a T`)
     style = "ref",
     // if you do not want to generate getters, you can use skip
     // skip,
+    // generate `take_*` fns, which use `core::mem::take` to move the field out
+    // take,
+    // make the group getters return a `<Struct><Group>` view struct instead of a tuple,
+    // e.g. `ServerTlsOpts` for the group `tls_opts`
+    // group_view,
+    // generated methods which shadow common trait methods (e.g. `clone`) or `new` are rejected,
+    // list the intended ones here
+    // allow_shadow = "clone, new",
+    // attach attributes to all the getters, can also be set on a single field by `getter(attrs(..))`,
+    // the struct level `attrs` and `inline` also apply to `dirty_fields` and `is_dirty_*`
+    // (and of the setters, to `clear_dirty`)
+    // attrs(must_use, doc(hidden)),
+    // the getters are `#[inline]` by default, available values here are always, never or none
+    // inline = "always",
+    // the fields of these types have no getters and setters, unless the field opts in
+    // by `getter(skip = false)` or `setter(skip = false)`, defaults to PhantomData and PhantomPinned
+    // skip_types = "PhantomData, PhantomPinned",
   ),
+  // inject a hidden bitset field, make every setter mark its field dirty (the `lock` guards do not),
+  // and generate `dirty_fields`, `is_dirty_*` and `clear_dirty` fns, the bitset is always equal
+  // and hashes to nothing, so the derived `PartialEq`, `Ord` and `Hash` ignore it, and it is
+  // `#[serde(skip)]` when the struct derives `Serialize` or `Deserialize`
+  // track_changes,
+  // the field attributes copied onto the generated getters and setters,
+  // defaults to cfg, cfg_attr, deprecated and allow
+  // forward_attrs(cfg, cfg_attr, deprecated, allow),
+  // keep the fields private (or `readonly = "pub(crate)"`) while the getters take the visibility,
+  // the getters which write the field, `take_*`, `*_mut`, `*_at_mut`, `write_*` and `*_pin_mut`,
+  // take the setter visibility, can also be set on a single field by `#[viewit(readonly)]`
+  // readonly,
+  // generate a getter for a value computed from the fields, can be repeated,
+  // add `memo` to compute it once and keep it in an injected `OnceCell` field, which every
+  // generated mutator resets, but direct writes to the fields and the `lock` guards do not,
+  // the cell is ignored by the derived comparisons, hashing and serde, and is empty when cloned
+  // computed(name = "f2_and_f3", ty = "String", expr = "format!(\"{} {}\", self.f2, self.f3)"),
+  // generate `f2_f3(&self) -> (&String, &String)`, `f2_f3_mut(&mut self)` and `set_f2_f3((String, String))`
+  // for several fields at once, which must not be `cfg`-gated, can be repeated
+  // multi(name = "f2_f3", fields = "f2, f3"),
+  // generate a `Debug` impl, which prints `<redacted>` for the fields marked `sensitive`
+  // debug_impl,
   // print the generated code to std out, other available values here are: stderr or "path/to/output/file"
   debug = "stdout"
 )]
@@ -44,10 +100,27 @@ struct Foo {
       style = "move",
       rename = "get_first_field",
       vis = "pub" // we do not want the getter for the first field is public, then we can custom field getter
+      // add a where clause to this getter, use `impl_where` to generate the getter
+      // in a separate impl block with the extra bounds instead
+      // where = "Self: Clone",
     ),
     setter(
       skip, // we do not want the setter for the field, then we skip it.
-    )
+    ),
+    // generate typed accessors for a range of bits of an integer field,
+    // use `bit = 7` instead of `range` for a single `bool` bit, the range must fit in `ty`,
+    // and the accessors follow `getter(skip)` and `setter(skip)` of the field
+    // bits(name = "version", range = "0..4", ty = "u8"),
+    // generate a `f1_pin_mut(self: Pin<&mut Self>)` projection, fields marked `pin` are
+    // structurally pinned, the others are projected to `&mut`, the projections mark the field dirty
+    // pin,
+    // redact the field in the `Debug` impl of `debug_impl`, the getters are private
+    // unless `getter(vis = "..")` is given, as are the group and `multi` getters of the field
+    // unless `multi(vis = "..")` is given
+    // sensitive,
+    // add the field to a group, the fields of a group are returned together by `fn net(&self)`,
+    // and set together by `fn set_net(..)`, a field can be in several groups, but not a `cfg`-gated one
+    // group = "net",
   )]
   f1: u8,
   #[viewit(
@@ -75,6 +148,21 @@ struct Foo {
 
   #[viewit(
     getter(
+      // for `Vec`, `VecDeque`, sets and maps, `iter` generates a getter returning
+      // `impl Iterator`, and the `*_mut` (which marks the field dirty), `*_len` and `*_is_empty` helpers
+      // iter,
+      // for `Mutex` and `RwLock`, `lock` generates a getter returning the guard
+      // (`read_*` and `write_*` for `RwLock`), a poisoned lock panics by default,
+      // use `lock = "into_inner"` to ignore the poisoning or `lock = "result"` to return the `LockResult`,
+      // only the `std::sync` locks are supported, the guards do not mark the field dirty,
+      // and `on_change` is not supported
+      // lock,
+      // for arrays and slices, `index` generates the `*_at` and `*_at_mut` getters returning `Option`
+      // and a `set_*_at` setter returning `{Struct}IndexError` when out of bounds, plus a `{FIELD}_LEN` const for arrays
+      // index,
+      // for `[u8; N]` fields, read the bytes as a big endian `u32` (`be`, `le` or `ne`),
+      // the setter takes a `u32` as well
+      // endian = "be", ty = "u32",
       result(
         // we want to convert the f3 field to String
         type = "String",
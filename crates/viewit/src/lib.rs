@@ -1,15 +1,103 @@
-use darling::{FromDeriveInput, FromField, FromMeta};
+#![allow(clippy::manual_unwrap_or_default)]
+
+use darling::{
+  util::{PathList, SpannedValue},
+  FromDeriveInput, FromField, FromMeta,
+};
 use derivit_core::{
-  getter::{FieldGetter, FieldGetterOptions, StructGetterOptions},
-  setter::{FieldSetter, FieldSetterOptions, StructSetterOptions},
+  bits::{BitsOptions, FieldBits},
+  collection::Collection,
+  computed::{memo_cell, memo_cell_ty, ComputedGetter, ComputedOptions, Memo},
+  endian::EndianConverter,
+  getter::{FieldGetter, FieldGetterOptions, GetterConverter, StructGetterOptions, Style},
+  group::{FieldGroup, GroupField},
+  index::{index_error, Index},
+  lock::Lock,
+  multi::{MultiAccessor, MultiOptions},
+  packed::is_packed,
+  pin::{pin_guards, PinProjection},
+  redact::{DebugField, DebugImpl},
+  setter::{
+    cow_inner, FieldSetter, FieldSetterOptions, SetterStyle, StructSetterOptions, TryIntoError,
+  },
+  tracker::ChangeTracker,
+  Readonly,
 };
-use quote::{format_ident, quote};
-use syn::parse_macro_input;
+use proc_macro2::{Group, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{parse_macro_input, spanned::Spanned};
+
+const DEFAULT_FORWARD_ATTRS: &[&str] = &["cfg", "cfg_attr", "deprecated", "allow"];
 
 trait ViewIt {
   fn vis_all(&self) -> Option<&syn::Visibility>;
   fn setter(&self) -> &StructSetterOptions;
   fn getter(&self) -> &StructGetterOptions;
+  fn forward_attrs(&self) -> Option<&PathList>;
+  fn computed(&self) -> &[ComputedOptions];
+  fn multi(&self) -> &[MultiOptions];
+  fn debug_impl(&self) -> bool;
+  fn readonly(&self) -> Option<&Readonly> {
+    None
+  }
+
+  /// The attributes of the accessors which are not generated for a single field.
+  fn getter_attrs(&self) -> Vec<syn::Attribute> {
+    let getter = self.getter();
+    getter
+      .attrs
+      .attrs
+      .iter()
+      .cloned()
+      .chain(getter.inline.to_attr())
+      .collect()
+  }
+
+  fn setter_attrs(&self) -> Vec<syn::Attribute> {
+    let setter = self.setter();
+    setter
+      .attrs
+      .attrs
+      .iter()
+      .cloned()
+      .chain(setter.inline.to_attr())
+      .collect()
+  }
+
+  fn is_forwarded(&self, path: &syn::Path) -> bool {
+    match self.forward_attrs() {
+      Some(paths) => paths.contains(path),
+      None => DEFAULT_FORWARD_ATTRS.iter().any(|name| path.is_ident(name)),
+    }
+  }
+
+  /// Returns the attribute which should be copied onto the accessors of a field,
+  /// for `cfg_attr`, only the forwarded attributes inside it are kept.
+  fn forward_attr(&self, attr: &syn::Attribute) -> Option<syn::Attribute> {
+    if !self.is_forwarded(&attr.path) {
+      return None;
+    }
+
+    if !attr.path.is_ident("cfg_attr") {
+      return Some(attr.clone());
+    }
+
+    let Ok(syn::Meta::List(list)) = attr.parse_meta() else {
+      return None;
+    };
+    let mut nested = list.nested.iter();
+    let predicate = nested.next()?;
+    let attrs = nested
+      .filter(|meta| match meta {
+        syn::NestedMeta::Meta(meta) => self.is_forwarded(meta.path()),
+        syn::NestedMeta::Lit(_) => false,
+      })
+      .collect::<Vec<_>>();
+    if attrs.is_empty() {
+      return None;
+    }
+    Some(syn::parse_quote!(#[cfg_attr(#predicate, #(#attrs),*)]))
+  }
 }
 
 #[derive(FromDeriveInput)]
@@ -20,6 +108,13 @@ struct ViewItDerive {
   setter: StructSetterOptions,
   #[darling(default, rename = "getters")]
   getter: StructGetterOptions,
+  forward_attrs: Option<PathList>,
+  #[darling(multiple)]
+  computed: Vec<ComputedOptions>,
+  #[darling(multiple)]
+  multi: Vec<MultiOptions>,
+  #[darling(default)]
+  debug_impl: bool,
   debug: Option<derivit_core::Debug>,
 }
 
@@ -33,12 +128,31 @@ impl ViewIt for ViewItDerive {
   fn getter(&self) -> &StructGetterOptions {
     &self.getter
   }
+  fn forward_attrs(&self) -> Option<&PathList> {
+    self.forward_attrs.as_ref()
+  }
+  fn computed(&self) -> &[ComputedOptions] {
+    &self.computed
+  }
+  fn multi(&self) -> &[MultiOptions] {
+    &self.multi
+  }
+  fn debug_impl(&self) -> bool {
+    self.debug_impl
+  }
 }
 
+#[derive(Default)]
 struct ViewItAttribute {
   vis_all: Option<syn::Visibility>,
   setter: StructSetterOptions,
   getter: StructGetterOptions,
+  track_changes: bool,
+  forward_attrs: Option<PathList>,
+  readonly: Option<Readonly>,
+  computed: Vec<ComputedOptions>,
+  multi: Vec<MultiOptions>,
+  debug_impl: bool,
   debug: Option<derivit_core::Debug>,
 }
 
@@ -52,6 +166,21 @@ impl ViewIt for ViewItAttribute {
   fn getter(&self) -> &StructGetterOptions {
     &self.getter
   }
+  fn forward_attrs(&self) -> Option<&PathList> {
+    self.forward_attrs.as_ref()
+  }
+  fn computed(&self) -> &[ComputedOptions] {
+    &self.computed
+  }
+  fn multi(&self) -> &[MultiOptions] {
+    &self.multi
+  }
+  fn debug_impl(&self) -> bool {
+    self.debug_impl
+  }
+  fn readonly(&self) -> Option<&Readonly> {
+    self.readonly.as_ref()
+  }
 }
 
 impl FromMeta for ViewItAttribute {
@@ -59,35 +188,75 @@ impl FromMeta for ViewItAttribute {
     let mut vis_all: (bool, Option<syn::Visibility>) = (false, None);
     let mut getters = (false, None);
     let mut setters = (false, None);
+    let mut track_changes = (false, None);
+    let mut forward_attrs = (false, None);
+    let mut readonly = (false, None);
+    let mut computed = Vec::new();
+    let mut multi = Vec::new();
+    let mut debug_impl = (false, None);
     let mut debug = (false, None);
+    let mut errors = darling::Error::accumulator();
 
     for item in items {
       match item {
         syn::NestedMeta::Meta(inner) => {
           let name = darling::util::path_to_string(inner.path());
-          match name.as_str() {
-            "vis_all" => derivit_core::parser::Parser::parse(&name, inner, &mut vis_all)?,
-            "setters" => derivit_core::parser::Parser::parse(&name, inner, &mut setters)?,
-            "getters" => derivit_core::parser::Parser::parse(&name, inner, &mut getters)?,
-            "debug" => derivit_core::parser::Parser::parse(&name, inner, &mut debug)?,
-            other => {
-              return Err(
-                darling::Error::unknown_field_with_alts(other, &["getters", "setters", "vis_all"])
-                  .with_span(inner),
-              );
+          let result = match name.as_str() {
+            "vis_all" => derivit_core::parser::Parser::parse(&name, inner, &mut vis_all),
+            "setters" => derivit_core::parser::Parser::parse(&name, inner, &mut setters),
+            "getters" => derivit_core::parser::Parser::parse(&name, inner, &mut getters),
+            "track_changes" => {
+              derivit_core::parser::Parser::parse(&name, inner, &mut track_changes)
             }
-          }
+            "forward_attrs" => {
+              derivit_core::parser::Parser::parse(&name, inner, &mut forward_attrs)
+            }
+            "readonly" => derivit_core::parser::Parser::parse(&name, inner, &mut readonly),
+            "computed" => ComputedOptions::from_meta(inner)
+              .map(|c| computed.push(c))
+              .map_err(|e| e.with_span(inner).at(&name)),
+            "multi" => MultiOptions::from_meta(inner)
+              .map(|m| multi.push(m))
+              .map_err(|e| e.with_span(inner).at(&name)),
+            "debug_impl" => derivit_core::parser::Parser::parse(&name, inner, &mut debug_impl),
+            "debug" => derivit_core::parser::Parser::parse(&name, inner, &mut debug),
+            other => Err(
+              darling::Error::unknown_field_with_alts(
+                other,
+                &[
+                  "getters",
+                  "setters",
+                  "vis_all",
+                  "track_changes",
+                  "forward_attrs",
+                  "readonly",
+                  "computed",
+                  "multi",
+                  "debug_impl",
+                  "debug",
+                ],
+              )
+              .with_span(inner),
+            ),
+          };
+          errors.handle(result);
         }
         syn::NestedMeta::Lit(inner) => {
-          return Err(darling::Error::unsupported_format("literal").with_span(inner))
+          errors.push(darling::Error::unsupported_format("literal").with_span(inner))
         }
       }
     }
 
-    Ok(Self {
+    errors.finish_with(Self {
       vis_all: vis_all.1,
       setter: setters.1.unwrap_or_default(),
       getter: getters.1.unwrap_or_default(),
+      track_changes: track_changes.1.unwrap_or_default(),
+      forward_attrs: forward_attrs.1,
+      readonly: readonly.1,
+      computed,
+      multi,
+      debug_impl: debug_impl.1.unwrap_or_default(),
       debug: debug.1,
     })
   }
@@ -98,99 +267,968 @@ impl FromMeta for ViewItAttribute {
 struct ViewField {
   #[darling(rename = "vis")]
   vis_: Option<syn::Visibility>,
+  readonly: Option<Readonly>,
   #[darling(default)]
   getter: FieldGetterOptions,
   #[darling(default)]
   setter: FieldSetterOptions,
+  #[darling(multiple)]
+  bits: Vec<BitsOptions>,
+  #[darling(default)]
+  pin: SpannedValue<bool>,
+  #[darling(multiple)]
+  group: Vec<syn::Ident>,
+  #[darling(default)]
+  sensitive: bool,
+}
+
+struct Accessors {
+  fields: Vec<syn::Field>,
+  getters: Vec<FieldGetter>,
+  setters: Vec<FieldSetter>,
+  bits: Vec<FieldBits>,
+  pins: Vec<PinProjection>,
+  groups: Vec<FieldGroup>,
+  computed: Vec<ComputedGetter>,
+  indexes: Vec<Index>,
+  multis: Vec<MultiAccessor>,
+  /// The sensitive fields, which are redacted by `debug_impl`.
+  sensitive: Vec<syn::Ident>,
+  debug_impl: Option<DebugImpl>,
+  index_error: Option<proc_macro2::TokenStream>,
+}
+
+fn is_deprecated(attr: &syn::Attribute) -> bool {
+  if attr.path.is_ident("deprecated") {
+    return true;
+  }
+
+  match attr.parse_meta() {
+    Ok(syn::Meta::List(list)) if list.path.is_ident("cfg_attr") => list.nested.iter().skip(1).any(
+      |meta| matches!(meta, syn::NestedMeta::Meta(meta) if meta.path().is_ident("deprecated")),
+    ),
+    _ => false,
+  }
+}
+
+/// Whether the forwarded attribute can be applied to a statement of the `Debug` impl.
+fn is_stmt_attr(attr: &syn::Attribute) -> bool {
+  let is_stmt_path = |path: &syn::Path| path.is_ident("cfg") || path.is_ident("allow");
+  if is_stmt_path(&attr.path) {
+    return true;
+  }
+
+  match attr.parse_meta() {
+    Ok(syn::Meta::List(list)) if list.path.is_ident("cfg_attr") => list
+      .nested
+      .iter()
+      .skip(1)
+      .all(|meta| matches!(meta, syn::NestedMeta::Meta(meta) if is_stmt_path(meta.path()))),
+    _ => false,
+  }
+}
+
+/// The `cfg` attribute of a field, which cannot be forwarded to the accessors of several fields.
+fn cfg_gate(attrs: &[syn::Attribute]) -> Option<&syn::Attribute> {
+  attrs.iter().find(|attr| attr.path.is_ident("cfg"))
 }
 
 fn handle_fields<'a>(
   viewit: &impl ViewIt,
+  name: &syn::Ident,
+  generics: &syn::Generics,
+  tracker: Option<&ChangeTracker>,
+  packed: bool,
   fields: impl Iterator<Item = &'a mut syn::Field>,
-) -> darling::Result<(Vec<syn::Field>, Vec<FieldGetter>, Vec<FieldSetter>)> {
+) -> darling::Result<Accessors> {
   let mut struct_fields = Vec::new();
   let mut struct_getters = Vec::new();
   let mut struct_setters = Vec::new();
-  for f in fields {
+  let mut struct_bits = Vec::new();
+  let mut struct_pins = Vec::new();
+  let mut struct_groups: Vec<FieldGroup> = Vec::new();
+  let mut struct_indexes = Vec::new();
+  let mut struct_sensitive = Vec::new();
+  let mut debug_fields = Vec::new();
+  let private = syn::Visibility::Inherited;
+  // every mutator resets the memoised computed values
+  let memo = Memo {
+    cells: viewit
+      .computed()
+      .iter()
+      .filter_map(ComputedOptions::cell)
+      .collect(),
+  };
+  // the fields which the `multi` accessors look up, with whether the setter is available
+  // and whether the field is sensitive
+  let mut multi_fields: Vec<(GroupField, bool, bool)> = Vec::new();
+  // the `cfg`-gated fields, which the `multi` accessors reject
+  let mut cfg_fields = Vec::new();
+  // report the errors of all the fields together, instead of stopping at the first one
+  let mut errors = darling::Error::accumulator();
+  let struct_getter_attrs = viewit.getter_attrs();
+  let struct_setter_attrs = viewit.setter_attrs();
+  for (idx, f) in fields.enumerate() {
     let field_name = f.ident.as_ref().unwrap();
-    let field = match ViewField::from_field(f) {
-      Ok(field) => field,
-      Err(e) => return Err(e),
+    let Some(field) = errors.handle(ViewField::from_field(f)) else {
+      continue;
     };
+    let mut forwarded = f
+      .attrs
+      .iter()
+      .filter_map(|attr| viewit.forward_attr(attr))
+      .collect::<Vec<_>>();
+    // the accessors of a deprecated field are deprecated themselves,
+    // so the field access inside them should not warn.
+    if forwarded.iter().any(is_deprecated) {
+      forwarded.push(syn::parse_quote!(#[allow(deprecated)]));
+    }
+    let getter_attrs = forwarded
+      .iter()
+      .chain(&viewit.getter().attrs.attrs)
+      .chain(&field.getter.attrs.attrs)
+      .cloned()
+      .chain(
+        field
+          .getter
+          .inline
+          .unwrap_or(viewit.getter().inline)
+          .to_attr(),
+      )
+      .collect::<Vec<_>>();
+    let setter_attrs = forwarded
+      .iter()
+      .chain(&viewit.setter().attrs.attrs)
+      .chain(&field.setter.attrs.attrs)
+      .cloned()
+      .chain(
+        field
+          .setter
+          .inline
+          .unwrap_or(viewit.setter().inline)
+          .to_attr(),
+      )
+      .collect::<Vec<_>>();
+    if field.sensitive {
+      struct_sensitive.push(field_name.clone());
+    }
+    debug_fields.push(DebugField {
+      name: field_name.clone(),
+      sensitive: field.sensitive,
+      attrs: forwarded
+        .iter()
+        .filter(|attr| is_stmt_attr(attr))
+        .cloned()
+        .collect(),
+    });
+    let readonly = field.readonly.as_ref().or_else(|| viewit.readonly());
+    // the getters of a sensitive field are private unless the visibility is given explicitly
+    let getter_vis = match (&field.getter.vis, field.sensitive) {
+      (Some(vis), _) => vis,
+      (None, true) => &private,
+      (None, false) => viewit
+        .getter()
+        .vis_all
+        .as_ref()
+        .unwrap_or_else(|| viewit.vis_all().unwrap_or(&f.vis)),
+    };
+    let getter_name = |name: &syn::Ident| {
+      if let Some(p) = &viewit.getter().prefix {
+        format_ident!("{}_{}", p, name, span = name.span())
+      } else {
+        name.clone()
+      }
+    };
+    let setter_vis = field.setter.vis.as_ref().unwrap_or_else(|| {
+      viewit.setter().vis_all.as_ref().unwrap_or_else(|| {
+        readonly
+          .map(|r| &r.vis)
+          .unwrap_or_else(|| viewit.vis_all().unwrap_or(&f.vis))
+      })
+    });
+    // the getters which also write the field, e.g. `take_*` and `*_mut`, of a readonly field
+    // have the setter visibility, and they stay private if the field is sensitive
+    let mut_vis = match readonly {
+      Some(_) if field.sensitive => &private,
+      Some(_) => setter_vis,
+      None => getter_vis,
+    };
+    let setter_name = |name: &syn::Ident| {
+      format_ident!(
+        "{}_{}",
+        viewit
+          .setter()
+          .prefix
+          .clone()
+          .unwrap_or_else(|| format_ident!("set")),
+        name,
+        span = name.span()
+      )
+    };
+    let cow = cow_inner(&f.ty).is_some();
+    let setter_style = match (field.setter.style, viewit.setter().style) {
+      (Some(SetterStyle::Cow), _) if !cow => {
+        errors.push(
+          darling::Error::custom("`cow` setter expects an `Arc` or `Rc` field").with_span(&f.ty),
+        );
+        SetterStyle::Ref
+      }
+      (Some(style), _) => style,
+      // the struct level `cow` only applies to the `Arc` and `Rc` fields
+      (None, SetterStyle::Cow) if !cow => SetterStyle::Ref,
+      (None, style) => style,
+    };
+    let on_change = field
+      .setter
+      .on_change
+      .clone()
+      .or_else(|| viewit.setter().on_change.clone());
+    // the `*_mut` accessor of the `cow` setter hands out the value without calling the hook
+    if on_change.is_some() && matches!(setter_style, SetterStyle::Cow) {
+      errors.push(
+        darling::Error::custom("`cow` setter does not support `on_change`").with_span(field_name),
+      );
+    }
+    if packed && matches!(setter_style, SetterStyle::Cow) {
+      errors.push(
+        darling::Error::custom("`cow` setter is not supported for packed struct")
+          .with_span(field_name),
+      );
+    }
+    // the marker fields, e.g. `PhantomData`, have no accessors unless they opt in by `skip = false`
+    let marker = viewit.getter().is_skipped_type(&f.ty);
+    let getter_ignore = field.getter.ignore.unwrap_or(marker);
+    let setter_ignore = field.setter.ignore.unwrap_or(marker);
+
+    if *field.pin && packed {
+      errors.push(
+        darling::Error::custom("pin projections are not supported for packed struct")
+          .with_span(&field.pin),
+      );
+    }
+    struct_pins.push(PinProjection {
+      vis: mut_vis.clone(),
+      fn_name: getter_name(&format_ident!("{}_pin_mut", field_name)),
+      field_name: field_name.clone(),
+      field_ty: f.ty.clone(),
+      pinned: *field.pin,
+      track: tracker.map(|t| t.dirty_bit(idx)),
+      memo: memo.clone(),
+      attrs: getter_attrs.clone(),
+    });
 
-    match (viewit.getter().ignore, field.getter.ignore) {
+    for bits in &field.bits {
+      let Some(mut field_bits) = errors.handle(bits.to_field_bits(field_name, &f.ty)) else {
+        continue;
+      };
+      if !viewit.getter().ignore && !getter_ignore {
+        field_bits.getter = Some((getter_vis.clone(), getter_name(&bits.name)));
+      }
+      if !viewit.setter().ignore && !setter_ignore {
+        field_bits.setter = Some((setter_vis.clone(), setter_name(&bits.name), setter_style));
+      }
+      field_bits.track = tracker.map(|t| t.dirty_bit(idx));
+      field_bits.on_change = on_change.clone();
+      field_bits.memo = memo.clone();
+      field_bits.getter_attrs = getter_attrs.clone();
+      field_bits.setter_attrs = setter_attrs.clone();
+      if field_bits.getter.is_some() || field_bits.setter.is_some() {
+        struct_bits.push(field_bits);
+      }
+    }
+
+    for group in &field.group {
+      if packed {
+        errors.push(
+          darling::Error::custom("field groups are not supported for packed struct")
+            .with_span(group),
+        );
+        continue;
+      }
+      if cfg_gate(&f.attrs).is_some() {
+        errors.push(
+          darling::Error::custom(format!(
+            "cfg-gated field `{field_name}` cannot be in a field group"
+          ))
+          .with_span(group),
+        );
+        continue;
+      }
+      let pos = match struct_groups.iter().position(|g| g.name == *group) {
+        Some(pos) => pos,
+        None => {
+          let mut field_group = FieldGroup::new(group.clone(), name.clone(), generics.clone());
+          field_group.view = viewit.getter().group_view;
+          field_group.memo = memo.clone();
+          field_group.getter_attrs = struct_getter_attrs.clone();
+          field_group.setter_attrs = struct_setter_attrs.clone();
+          let vis = viewit
+            .vis_all()
+            .cloned()
+            .unwrap_or(syn::Visibility::Inherited);
+          if !viewit.getter().ignore {
+            let getter_vis = viewit
+              .getter()
+              .vis_all
+              .clone()
+              .unwrap_or_else(|| vis.clone());
+            field_group.getter = Some((getter_vis, getter_name(group)));
+          }
+          if !viewit.setter().ignore {
+            let setter_vis = viewit.setter().vis_all.clone().unwrap_or_else(|| {
+              viewit
+                .readonly()
+                .map(|r| r.vis.clone())
+                .unwrap_or_else(|| vis.clone())
+            });
+            field_group.setter = Some((setter_vis, setter_name(group), viewit.setter().style));
+          }
+          struct_groups.push(field_group);
+          struct_groups.len() - 1
+        }
+      };
+      let field_group = &mut struct_groups[pos];
+      // the group setter is only generated when no field of the group is readonly
+      // or has its setter skipped
+      if setter_ignore || field.readonly.is_some() {
+        field_group.setter = None;
+      }
+      // the group getter and its view would expose a sensitive field
+      if field.sensitive {
+        if let Some((vis, _)) = &mut field_group.getter {
+          *vis = private.clone();
+        }
+      }
+      field_group.fields.push(GroupField {
+        field_name: field_name.clone(),
+        field_ty: f.ty.clone(),
+        track: tracker.map(|t| t.dirty_bit(idx)),
+        on_change: on_change.clone(),
+      });
+    }
+
+    if cfg_gate(&f.attrs).is_some() {
+      cfg_fields.push(field_name.clone());
+    } else {
+      multi_fields.push((
+        GroupField {
+          field_name: field_name.clone(),
+          field_ty: f.ty.clone(),
+          track: tracker.map(|t| t.dirty_bit(idx)),
+          on_change: on_change.clone(),
+        },
+        !setter_ignore && field.readonly.is_none(),
+        field.sensitive,
+      ));
+    }
+
+    let getter_endian = field.getter.endian.and_then(|endian| {
+      errors.handle(
+        field
+          .getter
+          .ty
+          .clone()
+          .ok_or_else(|| {
+            darling::Error::custom("`endian` getter expects a `ty`").with_span(&endian)
+          })
+          .and_then(|ty| EndianConverter::new(*endian, ty, &f.ty)),
+      )
+    });
+
+    if *field.getter.index {
+      let error = format_ident!("{}IndexError", name);
+      match Index::from_type(field_name, &f.ty, error) {
+        None => errors.push(
+          darling::Error::custom("`index` getter expects an array or slice field")
+            .with_span(&field.getter.index),
+        ),
+        Some(_) if packed => errors.push(
+          darling::Error::custom("`index` getter is not supported for packed struct")
+            .with_span(&field.getter.index),
+        ),
+        // the elements are written in place, without the old and new values of the field
+        Some(index) if index.mutable && on_change.is_some() => errors.push(
+          darling::Error::custom("`index` getter does not support `on_change`")
+            .with_span(&field.getter.index),
+        ),
+        Some(mut index) => {
+          if !viewit.getter().ignore && !getter_ignore {
+            let fn_name = field
+              .getter
+              .rename
+              .clone()
+              .unwrap_or_else(|| getter_name(field_name));
+            index.getter = Some((getter_vis.clone(), fn_name));
+            index.mut_vis = mut_vis.clone();
+          }
+          if !viewit.setter().ignore && !setter_ignore {
+            let fn_name = field
+              .setter
+              .rename
+              .clone()
+              .unwrap_or_else(|| setter_name(field_name));
+            index.setter = Some((setter_vis.clone(), fn_name));
+          }
+          index.track = tracker.map(|t| t.dirty_bit(idx));
+          index.memo = memo.clone();
+          index.getter_attrs = getter_attrs.clone();
+          index.setter_attrs = setter_attrs.clone();
+          if index.getter.is_some() || index.setter.is_some() {
+            struct_indexes.push(index);
+          }
+        }
+      }
+    }
+
+    match (viewit.getter().ignore, getter_ignore) {
       (true, true) | (false, true) | (true, false) => {}
       (false, false) => {
-        let vis = field.getter.vis.as_ref().unwrap_or_else(|| {
-          viewit
-            .getter()
-            .vis_all
-            .as_ref()
-            .unwrap_or_else(|| viewit.vis_all().unwrap_or(&f.vis))
-        });
-        let fn_name = field.getter.rename.clone().unwrap_or_else(|| {
-          if let Some(p) = &viewit.getter().prefix {
-            format_ident!("{}_{}", p, field_name)
-          } else {
-            field_name.clone()
+        let vis = getter_vis;
+        let fn_name = field
+          .getter
+          .rename
+          .clone()
+          .unwrap_or_else(|| getter_name(field_name));
+
+        let iter = match *field.getter.iter {
+          true if packed => {
+            errors.push(
+              darling::Error::custom("`iter` getter is not supported for packed struct")
+                .with_span(&field.getter.iter),
+            );
+            None
           }
-        });
+          true => errors.handle(Collection::from_type(&f.ty).ok_or_else(|| {
+            darling::Error::custom(
+              "`iter` getter expects a `Vec`, `VecDeque`, `LinkedList`, `BinaryHeap`, set or map field",
+            )
+            .with_span(&f.ty)
+          })),
+          false => None,
+        };
+
+        let lock = match field.getter.lock {
+          Some(lock) if packed => {
+            errors.push(
+              darling::Error::custom("`lock` getter is not supported for packed struct")
+                .with_span(&lock),
+            );
+            None
+          }
+          // the guards write the value without the old and new values of the field
+          Some(lock) if on_change.is_some() => {
+            errors.push(
+              darling::Error::custom("`lock` getter does not support `on_change`").with_span(&lock),
+            );
+            None
+          }
+          Some(poison) => errors.handle(Lock::from_type(&f.ty, *poison)),
+          None => None,
+        };
+
+        let mut style = field.getter.style.unwrap_or(viewit.getter().style);
+        let mut converter = field.getter.result.clone();
+        if let Some(endian) = &getter_endian {
+          converter = Some(GetterConverter::from_endian(endian));
+          style = Style::Ref;
+        }
 
-        let style = field.getter.style.unwrap_or(viewit.getter().style);
         struct_getters.push(FieldGetter {
           field_name: field_name.clone(),
           field_ty: f.ty.clone(),
           style,
           vis: vis.clone(),
+          mut_vis: mut_vis.clone(),
           fn_name,
-          converter: field.getter.result.clone(),
+          converter,
+          iter,
+          lock,
+          where_clause: field.getter.where_clause.where_clause.clone(),
+          impl_where: field.getter.impl_where.where_clause.clone(),
+          take: field.getter.take.unwrap_or(viewit.getter().take),
+          track: tracker.map(|t| t.dirty_bit(idx)),
+          memo: memo.clone(),
+          packed,
+          attrs: getter_attrs.clone(),
         });
       }
     }
 
-    match (viewit.setter().ignore, field.setter.ignore) {
+    match (viewit.setter().ignore, setter_ignore) {
       (true, true) | (false, true) | (true, false) => {}
       (false, false) => {
-        let vis = field.setter.vis.as_ref().unwrap_or_else(|| {
-          viewit
-            .setter()
-            .vis_all
-            .as_ref()
-            .unwrap_or_else(|| viewit.vis_all().unwrap_or(&f.vis))
-        });
-        let fn_name = field.setter.rename.clone().unwrap_or_else(|| {
-          format_ident!(
-            "{}_{}",
-            viewit
-              .setter()
-              .prefix
-              .clone()
-              .unwrap_or_else(|| format_ident!("set")),
-            field_name
-          )
-        });
+        let vis = setter_vis;
+        if readonly.is_some() && matches!(vis, syn::Visibility::Public(_)) {
+          errors.push(
+            darling::Error::custom(format!(
+              "readonly field `{field_name}` cannot have a public setter"
+            ))
+            .with_span(vis),
+          );
+        }
+        let fn_name = field
+          .setter
+          .rename
+          .clone()
+          .unwrap_or_else(|| setter_name(field_name));
+
+        // fall back to the endian options of the getter, which are already checked
+        let endian = match (field.setter.endian, &field.setter.ty) {
+          (None, None) => getter_endian,
+          (endian, ty) => endian.or(field.getter.endian).and_then(|endian| {
+            errors.handle(
+              ty.clone()
+                .or_else(|| field.getter.ty.clone())
+                .ok_or_else(|| {
+                  darling::Error::custom("`endian` setter expects a `ty`").with_span(&endian)
+                })
+                .and_then(|ty| EndianConverter::new(*endian, ty, &f.ty)),
+            )
+          }),
+        };
+
+        let error = match (
+          setter_style,
+          field
+            .setter
+            .error
+            .clone()
+            .or_else(|| viewit.setter().error.clone()),
+        ) {
+          (SetterStyle::TryInto, Some(ty)) => Some(TryIntoError {
+            ty,
+            map_err: field.setter.map_err.clone(),
+          }),
+          (SetterStyle::TryInto, None) => {
+            if let Some(map_err) = &field.setter.map_err {
+              errors
+                .push(darling::Error::custom("`map_err` expects an `error`").with_span(map_err));
+            }
+            None
+          }
+          // the struct level `error` only applies to the `try_into` setters
+          _ => {
+            if let Some(ty) = &field.setter.error {
+              errors.push(
+                darling::Error::custom("`error` expects the `try_into` setter style").with_span(ty),
+              );
+            } else if let Some(map_err) = &field.setter.map_err {
+              errors.push(
+                darling::Error::custom("`map_err` expects the `try_into` setter style")
+                  .with_span(map_err),
+              );
+            }
+            None
+          }
+        };
 
-        let style = field.setter.style.unwrap_or(viewit.setter().style);
         struct_setters.push(FieldSetter {
           field_name: field_name.clone(),
           field_ty: f.ty.clone(),
-          style,
+          style: setter_style,
           vis: vis.clone(),
           fn_name,
           bound: field.setter.bound.bound.clone(),
+          where_clause: field.setter.where_clause.where_clause.clone(),
+          impl_where: field.setter.impl_where.where_clause.clone(),
+          replace: field.setter.replace.unwrap_or(viewit.setter().replace),
+          track: tracker.map(|t| t.dirty_bit(idx)),
+          memo: memo.clone(),
+          on_change: on_change.clone(),
+          endian,
+          error,
+          packed,
+          attrs: setter_attrs,
         });
       }
     }
 
     f.attrs.retain(|x| !x.path.is_ident("viewit"));
-    f.vis = viewit
-      .vis_all()
-      .unwrap_or_else(|| field.vis_.as_ref().unwrap_or(&f.vis))
-      .clone();
+    f.vis = match readonly {
+      Some(readonly) => readonly.vis.clone(),
+      None => viewit
+        .vis_all()
+        .unwrap_or_else(|| field.vis_.as_ref().unwrap_or(&f.vis))
+        .clone(),
+    };
     struct_fields.push(f.clone());
   }
 
-  Ok((struct_fields, struct_getters, struct_setters))
+  // only generate the pin projections when there is any pinned field
+  if !struct_pins.iter().any(|p| p.pinned) {
+    struct_pins.clear();
+  }
+
+  let mut struct_computed = Vec::new();
+  for computed in viewit.computed() {
+    if computed.memo && packed {
+      errors.push(
+        darling::Error::custom("memoised computed getters are not supported for packed struct")
+          .with_span(&computed.name),
+      );
+    }
+    let vis = computed.vis.clone().unwrap_or_else(|| {
+      viewit
+        .getter()
+        .vis_all
+        .clone()
+        .or_else(|| viewit.vis_all().cloned())
+        .unwrap_or(syn::Visibility::Inherited)
+    });
+    let fn_name = match &viewit.getter().prefix {
+      Some(p) => format_ident!("{}_{}", p, computed.name),
+      None => computed.name.clone(),
+    };
+    struct_computed.push(computed.to_getter(vis, fn_name, struct_getter_attrs.clone()));
+  }
+
+  let mut struct_multis = Vec::new();
+  for multi in viewit.multi() {
+    if packed {
+      errors.push(
+        darling::Error::custom("`multi` accessors are not supported for packed struct")
+          .with_span(&multi.name),
+      );
+      continue;
+    }
+    let mut fields = Vec::new();
+    let mut settable = true;
+    let mut sensitive = false;
+    for (idx, ident) in multi.fields.idents.iter().enumerate() {
+      if multi.fields.idents[..idx].contains(ident) {
+        errors.push(
+          darling::Error::custom(format!("field `{ident}` is listed more than once"))
+            .with_span(ident),
+        );
+        continue;
+      }
+      match multi_fields.iter().find(|(f, ..)| f.field_name == *ident) {
+        Some((field, setter, field_sensitive)) => {
+          fields.push(field.clone());
+          settable &= *setter;
+          sensitive |= *field_sensitive;
+        }
+        None if cfg_fields.contains(ident) => errors.push(
+          darling::Error::custom(format!(
+            "cfg-gated field `{ident}` cannot be in a `multi` accessor"
+          ))
+          .with_span(ident),
+        ),
+        None => {
+          errors.push(darling::Error::custom(format!("unknown field `{ident}`")).with_span(ident))
+        }
+      }
+    }
+    if multi.fields.idents.is_empty() {
+      errors
+        .push(darling::Error::custom("`multi` expects at least one field").with_span(&multi.name));
+    }
+
+    let vis = viewit
+      .vis_all()
+      .cloned()
+      .unwrap_or(syn::Visibility::Inherited);
+    // the getters of the sensitive fields are private unless the visibility is given explicitly
+    let getter_vis = multi.vis.clone().unwrap_or_else(|| {
+      if sensitive {
+        return private.clone();
+      }
+      viewit
+        .getter()
+        .vis_all
+        .clone()
+        .unwrap_or_else(|| vis.clone())
+    });
+    let setter_vis = multi.vis.clone().unwrap_or_else(|| {
+      viewit.setter().vis_all.clone().unwrap_or_else(|| {
+        viewit
+          .readonly()
+          .map(|r| r.vis.clone())
+          .unwrap_or_else(|| vis.clone())
+      })
+    });
+    let fn_name = match &viewit.getter().prefix {
+      Some(p) => format_ident!("{}_{}", p, multi.name),
+      None => multi.name.clone(),
+    };
+    let prefix = viewit
+      .setter()
+      .prefix
+      .clone()
+      .unwrap_or_else(|| format_ident!("set"));
+    let settable = settable && !viewit.setter().ignore;
+    struct_multis.push(MultiAccessor {
+      name: multi.name.clone(),
+      fields,
+      getter: (!viewit.getter().ignore).then(|| (getter_vis, fn_name.clone())),
+      getter_mut: settable.then(|| {
+        let vis = if sensitive && multi.vis.is_none() {
+          private.clone()
+        } else {
+          setter_vis.clone()
+        };
+        (vis, format_ident!("{}_mut", fn_name))
+      }),
+      setter: settable.then(|| {
+        (
+          setter_vis,
+          format_ident!("{}_{}", prefix, multi.name),
+          viewit.setter().style,
+        )
+      }),
+      memo: memo.clone(),
+      getter_attrs: struct_getter_attrs.clone(),
+      setter_attrs: struct_setter_attrs.clone(),
+    });
+  }
+
+  let debug_impl = viewit.debug_impl().then(|| DebugImpl {
+    struct_name: name.clone(),
+    generics: generics.clone(),
+    fields: debug_fields,
+    packed,
+  });
+
+  // the error of the indexed setters is shared by all the fields
+  let index_error = struct_indexes.iter().any(Index::has_setter).then(|| {
+    let vis = viewit
+      .vis_all()
+      .cloned()
+      .unwrap_or(syn::Visibility::Inherited);
+    index_error(&vis, &format_ident!("{}IndexError", name))
+  });
+
+  errors.finish_with(Accessors {
+    fields: struct_fields,
+    getters: struct_getters,
+    setters: struct_setters,
+    bits: struct_bits,
+    pins: struct_pins,
+    groups: struct_groups,
+    computed: struct_computed,
+    index_error,
+    indexes: struct_indexes,
+    multis: struct_multis,
+    sensitive: struct_sensitive,
+    debug_impl,
+  })
+}
+
+/// Methods which are easy to shadow by accident, a generated method with the
+/// same name hides the trait method (or clashes with the constructor).
+const SHADOWED_METHODS: &[(&str, &str)] = &[
+  ("new", "the `new` constructor"),
+  ("default", "`Default::default`"),
+  ("clone", "`Clone::clone`"),
+  ("clone_from", "`Clone::clone_from`"),
+  ("eq", "`PartialEq::eq`"),
+  ("ne", "`PartialEq::ne`"),
+  ("cmp", "`Ord::cmp`"),
+  ("partial_cmp", "`PartialOrd::partial_cmp`"),
+  ("hash", "`Hash::hash`"),
+  ("fmt", "`Debug::fmt` and `Display::fmt`"),
+  ("to_string", "`ToString::to_string`"),
+  ("to_owned", "`ToOwned::to_owned`"),
+  ("into", "`Into::into`"),
+  ("try_into", "`TryInto::try_into`"),
+  ("as_ref", "`AsRef::as_ref`"),
+  ("as_mut", "`AsMut::as_mut`"),
+  ("borrow", "`Borrow::borrow`"),
+  ("borrow_mut", "`BorrowMut::borrow_mut`"),
+  ("deref", "`Deref::deref`"),
+  ("deref_mut", "`DerefMut::deref_mut`"),
+  ("drop", "`Drop::drop`"),
+  ("type_id", "`Any::type_id`"),
+];
+
+/// Checks the names of all the generated methods up front, so a clash is reported
+/// with both of its sources instead of a duplicate definition error.
+fn check_names(
+  viewit: &impl ViewIt,
+  accessors: &Accessors,
+  tracker: Option<&ChangeTracker>,
+) -> darling::Result<()> {
+  let mut names: Vec<(syn::Ident, String)> = Vec::new();
+  let mut add = |idents: Vec<syn::Ident>, source: String| {
+    names.extend(idents.into_iter().map(|ident| (ident, source.clone())));
+  };
+  for getter in &accessors.getters {
+    add(
+      getter.fn_names(),
+      format!("the getter of field `{}`", getter.field_name),
+    );
+  }
+  for setter in &accessors.setters {
+    add(
+      setter.fn_names(),
+      format!("the setter of field `{}`", setter.field_name),
+    );
+  }
+  for bits in &accessors.bits {
+    let idents = bits
+      .getter
+      .iter()
+      .map(|(_, ident)| ident.clone())
+      .chain(bits.setter.iter().map(|(_, ident, _)| ident.clone()))
+      .collect();
+    add(
+      idents,
+      format!("the bits `{}` of field `{}`", bits.name, bits.field_name),
+    );
+  }
+  for pin in &accessors.pins {
+    add(
+      vec![pin.fn_name.clone()],
+      format!("the pin projection of field `{}`", pin.field_name),
+    );
+  }
+  for group in &accessors.groups {
+    let idents = group
+      .getter
+      .iter()
+      .map(|(_, ident)| ident.clone())
+      .chain(group.setter.iter().map(|(_, ident, _)| ident.clone()))
+      .collect();
+    add(idents, format!("the group `{}`", group.name));
+  }
+  for index in &accessors.indexes {
+    add(
+      index.fn_names(),
+      format!("the index accessors of field `{}`", index.field_name),
+    );
+  }
+  for multi in &accessors.multis {
+    add(
+      multi.fn_names(),
+      format!("the multi accessor `{}`", multi.name),
+    );
+  }
+  for computed in &accessors.computed {
+    add(
+      vec![computed.fn_name.clone()],
+      format!("the computed getter `{}`", computed.fn_name),
+    );
+  }
+  if let Some(tracker) = tracker {
+    add(tracker.fn_names(), "`track_changes`".to_string());
+  }
+
+  let allow_shadow = &viewit.getter().allow_shadow;
+  let mut errors = darling::Error::accumulator();
+  for (idx, (name, source)) in names.iter().enumerate() {
+    if let Some((_, other)) = names[..idx].iter().find(|(other, _)| other == name) {
+      errors.push(
+        darling::Error::custom(format!(
+          "method `{name}` is generated for both {other} and {source}"
+        ))
+        .with_span(name),
+      );
+      continue;
+    }
+
+    let shadowed = SHADOWED_METHODS
+      .iter()
+      .find(|(method, _)| name == method && !allow_shadow.contains(name));
+    if let Some((_, method)) = shadowed {
+      errors.push(
+        darling::Error::custom(format!(
+          "method `{name}` generated for {source} shadows {method}, \
+           use `getters(allow_shadow = \"{name}\")` if it is intended"
+        ))
+        .with_span(name),
+      );
+    }
+  }
+  errors.finish()
+}
+
+/// Generates the impl blocks for the accessors, accessors with an `impl_where`
+/// are generated in a separate impl block with the extra bounds.
+fn impl_blocks(
+  name: &syn::Ident,
+  generics: &syn::Generics,
+  accessors: &Accessors,
+  extra: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+  let Accessors {
+    getters,
+    setters,
+    bits,
+    pins,
+    groups,
+    computed,
+    indexes,
+    multis,
+    debug_impl,
+    index_error,
+    ..
+  } = accessors;
+  let mut blocks: Vec<(Option<&syn::WhereClause>, proc_macro2::TokenStream)> =
+    vec![(None, proc_macro2::TokenStream::new())];
+  let accessors = getters
+    .iter()
+    .map(|g| (g.impl_where.as_ref(), g as &dyn ToTokens))
+    .chain(
+      setters
+        .iter()
+        .map(|s| (s.impl_where.as_ref(), s as &dyn ToTokens)),
+    );
+  for (impl_where, accessor) in accessors {
+    let key = impl_where.map(|w| w.to_token_stream().to_string());
+    let pos = blocks
+      .iter()
+      .position(|(w, _)| w.map(|w| w.to_token_stream().to_string()) == key)
+      .unwrap_or_else(|| {
+        blocks.push((impl_where, proc_macro2::TokenStream::new()));
+        blocks.len() - 1
+      });
+    accessor.to_tokens(&mut blocks[pos].1);
+  }
+  for bits in bits {
+    bits.to_tokens(&mut blocks[0].1);
+  }
+  for pin in pins {
+    pin.to_tokens(&mut blocks[0].1);
+  }
+  for group in groups {
+    group.to_tokens(&mut blocks[0].1);
+  }
+  for index in indexes {
+    index.to_tokens(&mut blocks[0].1);
+  }
+  for multi in multis {
+    multi.to_tokens(&mut blocks[0].1);
+  }
+  for computed in computed {
+    computed.to_tokens(&mut blocks[0].1);
+  }
+  blocks[0].1.extend(extra);
+
+  let (_, ty_generics, _) = generics.split_for_impl();
+  let guards = (!pins.is_empty()).then(|| pin_guards(name, generics, pins.iter()));
+  let views = groups.iter().filter_map(FieldGroup::to_view);
+  blocks
+    .into_iter()
+    .map(|(impl_where, accessors)| {
+      let mut generics = generics.clone();
+      if let Some(impl_where) = impl_where {
+        generics
+          .make_where_clause()
+          .predicates
+          .extend(impl_where.predicates.iter().cloned());
+      }
+      let (impl_generics, _, where_clause) = generics.split_for_impl();
+      quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+          #accessors
+        }
+      }
+    })
+    .chain(guards)
+    .chain(views)
+    .chain(index_error.clone())
+    .chain(debug_impl.as_ref().map(ToTokens::to_token_stream))
+    .collect()
 }
 
 #[proc_macro_derive(View, attributes(view))]
@@ -209,24 +1247,33 @@ pub fn view(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
       .into();
   };
 
-  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
   let name = &input.ident;
+  let packed = is_packed(&input.attrs);
+  if let Some(computed) = viewit.computed.iter().find(|c| c.memo) {
+    return syn::Error::new_spanned(
+      &computed.name,
+      "memoised computed getters require the `#[viewit]` attribute to inject the cell field",
+    )
+    .to_compile_error()
+    .into();
+  }
   match &mut data.fields {
     syn::Fields::Named(fields) => {
-      let (_, struct_getters, struct_setters) =
-        match handle_fields(&viewit, fields.named.iter_mut()) {
-          Ok(x) => x,
-          Err(e) => return e.write_errors().into(),
-        };
-
-      let ts = quote! {
-        impl #impl_generics #name #ty_generics #where_clause {
-
-          #(#struct_getters)*
-
-          #(#struct_setters)*
-        }
+      let accessors = match handle_fields(
+        &viewit,
+        name,
+        &input.generics,
+        None,
+        packed,
+        fields.named.iter_mut(),
+      )
+      .and_then(|accessors| check_names(&viewit, &accessors, None).map(|_| accessors))
+      {
+        Ok(x) => x,
+        Err(e) => return e.write_errors().into(),
       };
+
+      let ts = impl_blocks(name, &input.generics, &accessors, quote!());
       if let Some(ref debug) = viewit.debug {
         if let Err(e) = debug.write(&ts) {
           return e.to_compile_error().into();
@@ -247,14 +1294,17 @@ pub fn viewit(
   let mut input = parse_macro_input!(input as syn::DeriveInput);
   let args = parse_macro_input!(args as syn::AttributeArgs);
   let struct_attrs = &input.attrs;
-  let mut viewit = match ViewItAttribute::from_list(&args) {
-    Ok(viewit) => viewit,
-    Err(e) => return e.write_errors().into(),
-  };
-  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+  // keep checking the fields with the default options when the struct options are invalid,
+  // so all the errors are reported together.
+  let mut errors = darling::Error::accumulator();
+  let mut viewit = errors
+    .handle(ViewItAttribute::from_list(&args))
+    .unwrap_or_default();
   let generics = &input.generics;
+  let where_clause = &generics.where_clause;
   let name = &input.ident;
   let vis = &input.vis;
+  let packed = is_packed(struct_attrs);
 
   // by default, apply struct visibility to fields.
   viewit.vis_all.get_or_insert(vis.clone());
@@ -269,24 +1319,59 @@ pub fn viewit(
 
   match &mut data.fields {
     syn::Fields::Named(fields) => {
-      let (struct_fields, struct_getters, struct_setters) =
-        match handle_fields(&viewit, fields.named.iter_mut()) {
-          Ok(x) => x,
-          Err(e) => return e.write_errors().into(),
-        };
+      let tracker = viewit.track_changes.then(|| {
+        let mut tracker = ChangeTracker::new(
+          vis.clone(),
+          name,
+          fields
+            .named
+            .iter()
+            .map(|f| f.ident.clone().unwrap())
+            .collect(),
+        );
+        tracker.getter_attrs = viewit.getter_attrs();
+        tracker.setter_attrs = viewit.setter_attrs();
+        tracker
+      });
+      let accessors = errors.handle(handle_fields(
+        &viewit,
+        name,
+        generics,
+        tracker.as_ref(),
+        packed,
+        fields.named.iter_mut(),
+      ));
+      if let Some(accessors) = &accessors {
+        errors.handle(check_names(&viewit, accessors, tracker.as_ref()));
+      }
+      let mut accessors = match errors.finish() {
+        Ok(()) => accessors.unwrap(),
+        Err(e) => return e.write_errors().into(),
+      };
+      if let Some(tracker) = &tracker {
+        accessors.fields.push(tracker.to_field(struct_attrs));
+      }
+      let cell_ty = memo_cell_ty(name);
+      let cells = accessors
+        .computed
+        .iter()
+        .filter_map(|c| c.to_field(&cell_ty, struct_attrs))
+        .collect::<Vec<_>>();
+      let memo_cell = (!cells.is_empty()).then(|| memo_cell(&cell_ty));
+      accessors.fields.extend(cells);
 
+      let impls = impl_blocks(name, generics, &accessors, quote!(#tracker));
+      let tracker_ty = tracker.as_ref().map(ChangeTracker::to_type);
+      let struct_fields = &accessors.fields;
       let ts = quote! {
         #(#struct_attrs)*
-        #vis struct #name #generics {
+        #vis struct #name #generics #where_clause {
           #(#struct_fields),*
         }
 
-        impl #impl_generics #name #ty_generics #where_clause {
-
-          #(#struct_getters)*
-
-          #(#struct_setters)*
-        }
+        #impls
+        #tracker_ty
+        #memo_cell
       };
 
       if let Some(ref debug) = viewit.debug {
@@ -304,8 +1389,331 @@ pub fn viewit(
     }
     syn::Fields::Unit => quote! {
       #(#struct_attrs)*
-      #vis struct #name #generics;
+      #vis struct #name #generics #where_clause;
     }
     .into(),
   }
 }
+
+/// The input of `remote!`: `#[viewit(..)] pub struct path::Foo as FooExt { a: u8 }`.
+struct RemoteStruct {
+  attrs: Vec<syn::Attribute>,
+  vis: syn::Visibility,
+  path: syn::Path,
+  trait_name: syn::Ident,
+  fields: syn::FieldsNamed,
+}
+
+impl syn::parse::Parse for RemoteStruct {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let attrs = input.call(syn::Attribute::parse_outer)?;
+    let vis = input.parse()?;
+    input.parse::<syn::Token![struct]>()?;
+    let path: syn::Path = input.parse()?;
+    let name = &path.segments.last().unwrap().ident;
+    let trait_name = if input.parse::<Option<syn::Token![as]>>()?.is_some() {
+      input.parse()?
+    } else {
+      format_ident!("{}Ext", name)
+    };
+    let fields = input.parse()?;
+    Ok(Self {
+      attrs,
+      vis,
+      path,
+      trait_name,
+      fields,
+    })
+  }
+}
+
+/// The listed field which the body of a remote accessor reads by `self.field`.
+fn accessed_field(tokens: proc_macro2::TokenStream, fields: &[syn::Field]) -> Option<&syn::Field> {
+  let tokens = tokens.into_iter().collect::<Vec<_>>();
+  tokens
+    .windows(3)
+    .find_map(|window| match window {
+      [TokenTree::Ident(this), TokenTree::Punct(dot), TokenTree::Ident(name)]
+        if (this == "self" || this == "this") && dot.as_char() == '.' =>
+      {
+        fields.iter().find(|f| f.ident.as_ref() == Some(name))
+      }
+      _ => None,
+    })
+    .or_else(|| {
+      tokens.iter().find_map(|token| match token {
+        TokenTree::Group(group) => accessed_field(group.stream(), fields),
+        _ => None,
+      })
+    })
+}
+
+/// Moves the generated tokens to `span`, the field names keep their own spans.
+fn respan(
+  tokens: proc_macro2::TokenStream,
+  span: proc_macro2::Span,
+  fields: &[syn::Field],
+) -> proc_macro2::TokenStream {
+  tokens
+    .into_iter()
+    .map(|mut token| {
+      match &mut token {
+        TokenTree::Group(group) => {
+          let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span, fields));
+          respanned.set_span(span);
+          *group = respanned;
+        }
+        TokenTree::Ident(ident) if fields.iter().any(|f| f.ident.as_ref() == Some(ident)) => {}
+        token => token.set_span(span),
+      }
+      token
+    })
+    .collect()
+}
+
+/// The options at `path` in `items`, e.g. `["getters", "group_view"]`.
+fn find_options<'a>(
+  items: impl IntoIterator<Item = &'a syn::NestedMeta>,
+  path: &[&str],
+) -> Vec<&'a syn::Meta> {
+  let Some((name, rest)) = path.split_first() else {
+    return Vec::new();
+  };
+  items
+    .into_iter()
+    .filter_map(|item| match item {
+      syn::NestedMeta::Meta(meta) if meta.path().is_ident(name) => Some(meta),
+      _ => None,
+    })
+    .flat_map(|meta| match (meta, rest) {
+      (_, []) => vec![meta],
+      (syn::Meta::List(list), rest) => find_options(&list.nested, rest),
+      _ => Vec::new(),
+    })
+    .collect()
+}
+
+impl RemoteStruct {
+  /// Parses the options, the unsupported ones are reported to `errors` together with
+  /// the errors of the fields, so the options are returned unless they fail to parse.
+  fn viewit(&self, errors: &mut darling::error::Accumulator) -> Option<ViewItAttribute> {
+    let mut items = Vec::new();
+    for attr in &self.attrs {
+      if !attr.path.is_ident("viewit") {
+        errors.push(darling::Error::custom("expected `#[viewit(..)]` attribute").with_span(attr));
+        continue;
+      }
+      match errors.handle(attr.parse_meta().map_err(darling::Error::from)) {
+        Some(syn::Meta::List(list)) => items.extend(list.nested),
+        Some(meta) => errors.push(darling::Error::unsupported_format("non-list").with_span(&meta)),
+        None => {}
+      }
+    }
+    let viewit = errors.handle(ViewItAttribute::from_list(&items))?;
+    // report every unsupported option at its token
+    let unsupported = [
+      (
+        viewit.track_changes,
+        &["track_changes"][..],
+        "`track_changes` cannot inject a field into a remote struct",
+      ),
+      (
+        viewit.readonly.is_some(),
+        &["readonly"],
+        "`readonly` cannot change the fields of a remote struct",
+      ),
+      (
+        viewit.getter.group_view,
+        &["getters", "group_view"],
+        "`group_view` is not supported for remote struct",
+      ),
+      (
+        viewit.debug_impl,
+        &["debug_impl"],
+        "`debug_impl` is not supported for remote struct",
+      ),
+    ];
+    for (_, path, msg) in unsupported.into_iter().filter(|(enabled, ..)| *enabled) {
+      for meta in find_options(&items, path) {
+        errors.push(darling::Error::custom(msg).with_span(meta));
+      }
+    }
+    for computed in viewit.computed.iter().filter(|c| c.memo) {
+      errors.push(
+        darling::Error::custom("`memo` cannot inject a field into a remote struct")
+          .with_span(&computed.name),
+      );
+    }
+    Some(viewit)
+  }
+
+  fn expand(mut self) -> darling::Result<proc_macro2::TokenStream> {
+    let mut errors = darling::Error::accumulator();
+    let name = self.path.segments.last().unwrap().ident.clone();
+    let mut viewit = self.viewit(&mut errors);
+    let accessors = viewit.as_mut().and_then(|viewit| {
+      // the generated types, e.g. the error of the indexed setters, take the visibility of the trait
+      viewit.vis_all.get_or_insert(self.vis.clone());
+      errors.handle(handle_fields(
+        viewit,
+        &name,
+        &syn::Generics::default(),
+        None,
+        false,
+        self.fields.named.iter_mut(),
+      ))
+    });
+    if let (Some(viewit), Some(accessors)) = (&viewit, &accessors) {
+      errors.handle(check_names(viewit, accessors, None));
+      for pin in accessors.pins.iter().filter(|p| p.pinned) {
+        errors.push(
+          darling::Error::custom("pin projections are not supported for remote struct")
+            .with_span(&pin.field_name),
+        );
+      }
+      // the methods of the extension trait are always public
+      for field_name in &accessors.sensitive {
+        errors.push(
+          darling::Error::custom("sensitive fields are not supported for remote struct")
+            .with_span(field_name),
+        );
+      }
+      // the where clauses are parsed from strings, so the errors are spanned at the fields
+      let impl_where = accessors
+        .getters
+        .iter()
+        .filter(|g| g.impl_where.is_some())
+        .map(|g| &g.field_name)
+        .chain(
+          accessors
+            .setters
+            .iter()
+            .filter(|s| s.impl_where.is_some())
+            .map(|s| &s.field_name),
+        );
+      for field_name in impl_where {
+        errors.push(
+          darling::Error::custom("`impl_where` is not supported for remote struct")
+            .with_span(field_name),
+        );
+      }
+    }
+    errors.finish()?;
+    let (viewit, accessors) = (viewit.unwrap(), accessors.unwrap());
+
+    // generate the accessors as inherent fns, then split them into the
+    // trait declarations and the trait impl.
+    let impls = syn::parse2::<syn::File>(impl_blocks(
+      &name,
+      &syn::Generics::default(),
+      &accessors,
+      quote!(),
+    ))?;
+    let mut trait_items = Vec::new();
+    let mut impl_items = Vec::new();
+    // the items which are not accessors, e.g. the error types, are kept as they are
+    let mut items = Vec::new();
+    for item in impls.items {
+      let item = match item {
+        syn::Item::Impl(item) if item.trait_.is_none() => item,
+        item => {
+          items.push(item);
+          continue;
+        }
+      };
+      for item in item.items {
+        match item {
+          syn::ImplItem::Method(mut method) => {
+            method.vis = syn::Visibility::Inherited;
+            // a listed field of another type fails in the accessor at the type of the field,
+            // as the check below does, instead of at the whole macro
+            if let Some(field) = accessed_field(method.block.to_token_stream(), &accessors.fields) {
+              let span = field.ty.span();
+              method.sig = syn::parse2(respan(
+                method.sig.to_token_stream(),
+                span,
+                &accessors.fields,
+              ))?;
+              method.block = syn::parse2(respan(
+                method.block.to_token_stream(),
+                span,
+                &accessors.fields,
+              ))?;
+            }
+            let mut sig = method.sig.clone();
+            for input in sig.inputs.iter_mut() {
+              match input {
+                syn::FnArg::Receiver(receiver) if receiver.reference.is_none() => {
+                  receiver.mutability = None
+                }
+                syn::FnArg::Receiver(_) => {}
+                syn::FnArg::Typed(arg) => {
+                  if let syn::Pat::Ident(pat) = &mut *arg.pat {
+                    pat.mutability = None;
+                  }
+                }
+              }
+            }
+            let attrs = method
+              .attrs
+              .iter()
+              .filter(|attr| !attr.path.is_ident("inline"));
+            trait_items.push(quote!(#(#attrs)* #sig;));
+            impl_items.push(syn::ImplItem::Method(method));
+          }
+          syn::ImplItem::Const(mut constant) => {
+            constant.vis = syn::Visibility::Inherited;
+            let syn::ImplItemConst {
+              attrs, ident, ty, ..
+            } = &constant;
+            trait_items.push(quote!(#(#attrs)* const #ident: #ty;));
+            impl_items.push(syn::ImplItem::Const(constant));
+          }
+          _ => {}
+        }
+      }
+    }
+
+    let vis = &self.vis;
+    let path = &self.path;
+    let trait_name = &self.trait_name;
+    // fail at the listed fields when they do not exist or have another type
+    let checks = accessors.fields.iter().map(|f| {
+      let field_name = f.ident.as_ref().unwrap();
+      let field_ty = &f.ty;
+      quote_spanned!(field_ty.span()=> let _: &#field_ty = &this.#field_name;)
+    });
+    let ts = quote! {
+      #vis trait #trait_name {
+        #(#trait_items)*
+      }
+
+      impl #trait_name for #path {
+        #(#impl_items)*
+      }
+
+      #(#items)*
+
+      const _: () = {
+        #[allow(dead_code)]
+        fn check_fields(this: &#path) {
+          #(#checks)*
+        }
+      };
+    };
+
+    if let Some(ref debug) = viewit.debug {
+      debug.write(&ts)?;
+    }
+    Ok(ts)
+  }
+}
+
+#[proc_macro]
+pub fn remote(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let input = parse_macro_input!(input as RemoteStruct);
+  match input.expand() {
+    Ok(ts) => ts.into(),
+    Err(e) => e.write_errors().into(),
+  }
+}
@@ -0,0 +1,21 @@
+use viewit::viewit;
+
+#[viewit(
+  getters(attrs(must_use), inline = "always"),
+  setters(
+    attrs(must_use = "the setter returns the updated value"),
+    inline = "never"
+  )
+)]
+#[derive(Default)]
+struct Config {
+  #[viewit(getter(attrs(doc = "The port."), inline = "none"))]
+  port: u16,
+  name: String,
+}
+
+#[test]
+fn attrs() {
+  let config = Config::default().set_port(1).set_name("a".to_string());
+  assert_eq!((*config.port(), config.name().as_str()), (1, "a"));
+}
@@ -0,0 +1,38 @@
+use viewit::viewit;
+
+#[viewit(setters(style = "ref"))]
+#[derive(Default)]
+struct Header {
+  #[viewit(
+    bits(name = "version", range = "0..4", ty = "u8"),
+    bits(name = "compressed", bit = 7),
+    bits(name = "length", range = "8..=15", ty = "u8")
+  )]
+  word: u16,
+  #[viewit(getter(skip), bits(name = "hidden", bit = 0))]
+  other: u8,
+}
+
+#[test]
+fn bits() {
+  let mut header = Header::default();
+  header.set_version(5);
+  header.set_compressed(true);
+  header.set_length(0xff);
+  assert_eq!(header.version(), 5);
+  assert!(header.compressed());
+  assert_eq!(header.length(), 0xff);
+  assert_eq!(*header.word(), 0xff85);
+  header.set_compressed(false);
+  assert_eq!(*header.word(), 0xff05);
+
+  // the getter of the bits follows the getter of the field
+  header.set_hidden(true);
+  assert_eq!(header.other, 1);
+}
+
+#[test]
+#[should_panic(expected = "value overflows the bits `version` (0..4) of field `word`")]
+fn bits_overflow() {
+  Header::default().set_version(16);
+}
@@ -0,0 +1,101 @@
+use viewit::viewit;
+
+#[viewit(
+  computed(name = "area", ty = "u32", expr = "self.width * self.height"),
+  computed(
+    name = "full",
+    ty = "String",
+    expr = "format!(\"{} {}\", self.first, self.last)",
+    memo
+  ),
+  setters(replace)
+)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Person {
+  #[viewit(group = "names", getter(take))]
+  first: String,
+  #[viewit(group = "names")]
+  last: String,
+  #[viewit(getter(iter))]
+  tags: Vec<String>,
+  width: u32,
+  height: u32,
+}
+
+#[test]
+fn computed() {
+  let p = Person::default().set_width(2).set_height(3);
+  assert_eq!(p.area(), 6);
+  assert_eq!(p.set_width(4).area(), 12);
+}
+
+#[test]
+fn memo() {
+  let p = Person::default()
+    .set_first("a".to_string())
+    .set_last("b".to_string());
+  assert_eq!(p.full(), "a b");
+  assert!(std::ptr::eq(p.full(), p.full()));
+}
+
+#[test]
+fn memo_is_reset_by_setters() {
+  let p = Person::default()
+    .set_first("a".to_string())
+    .set_last("b".to_string());
+  assert_eq!(p.full(), "a b");
+
+  let mut p = p.set_first("c".to_string());
+  assert_eq!(p.full(), "c b");
+
+  p.replace_last("d".to_string());
+  assert_eq!(p.full(), "c d");
+
+  p.take_first();
+  assert_eq!(p.full(), " d");
+
+  let p = p.set_names("e".to_string(), "f".to_string());
+  assert_eq!(p.full(), "e f");
+}
+
+#[test]
+fn memo_is_reset_by_mut_getters() {
+  let mut p = Person::default()
+    .set_first("a".to_string())
+    .set_tags(vec!["x".to_string()]);
+  assert_eq!(p.full(), "a ");
+
+  // a mutator which does not touch the fields of the computed value still resets it
+  p.first = "b".to_string();
+  p.tags_mut().for_each(|tag| tag.push('!'));
+  assert_eq!(p.full(), "b ");
+}
+
+#[test]
+fn memo_is_ignored_by_derives() {
+  let p = Person::default();
+  assert_eq!(p.full(), " ");
+  assert_eq!(p, Person::default());
+  assert_eq!(p.cmp(&Person::default()), std::cmp::Ordering::Equal);
+  assert_eq!(p.clone().full(), " ");
+}
+
+#[viewit(
+  track_changes,
+  computed(
+    name = "dirty",
+    ty = "bool",
+    expr = "self.dirty_fields().next().is_some()",
+    memo
+  )
+)]
+#[derive(Default)]
+struct Tracked {
+  value: u8,
+}
+
+#[test]
+fn memo_does_not_collide_with_the_tracker() {
+  let tracked = Tracked::default().set_value(1);
+  assert!(*tracked.dirty());
+}
@@ -0,0 +1,35 @@
+use std::rc::Rc;
+use std::sync::Arc;
+use viewit::viewit;
+
+#[viewit(setters(style = "cow"))]
+#[derive(Default, Clone)]
+struct Snapshot {
+  items: Arc<Vec<u32>>,
+  name: Rc<String>,
+}
+
+#[test]
+fn cow() {
+  let mut snapshot = Snapshot::default();
+  snapshot.set_items(vec![1]);
+  let shared = snapshot.clone();
+  snapshot.items_mut().push(2);
+  snapshot.name_mut().push('a');
+  assert_eq!(**snapshot.items(), [1, 2]);
+  assert_eq!(**shared.items(), [1]);
+  assert_eq!(snapshot.name().as_str(), "a");
+}
+
+#[viewit(track_changes, setters(style = "cow"))]
+#[derive(Default)]
+struct Tracked {
+  items: Arc<Vec<u32>>,
+}
+
+#[test]
+fn cow_marks_the_field_dirty() {
+  let mut tracked = Tracked::default();
+  tracked.items_mut().push(1);
+  assert!(tracked.is_dirty_items());
+}
@@ -0,0 +1,63 @@
+use viewit::viewit;
+
+#[viewit(debug_impl)]
+struct Login {
+  user: String,
+  #[viewit(sensitive)]
+  password: String,
+  #[cfg(not(test))]
+  missing: u8,
+  #[cfg(test)]
+  present: u8,
+}
+
+#[test]
+fn debug_impl_redacts_the_sensitive_fields() {
+  let login = Login {
+    user: "alice".to_string(),
+    password: "secret".to_string(),
+    present: 1,
+  };
+  assert_eq!(
+    format!("{login:?}"),
+    r#"Login { user: "alice", password: <redacted>, present: 1 }"#
+  );
+  assert_eq!(login.user(), "alice");
+  assert_eq!(login.password(), "secret");
+}
+
+mod account {
+  use viewit::viewit;
+
+  #[viewit(
+    vis_all = "pub",
+    multi(name = "login", fields = "user, token"),
+    multi(name = "public_login", fields = "user, token", vis = "pub")
+  )]
+  #[derive(Default)]
+  pub struct Account {
+    #[viewit(group = "credentials")]
+    user: String,
+    #[viewit(group = "credentials", sensitive)]
+    token: String,
+  }
+
+  pub fn credentials(account: &Account) -> (&String, &String) {
+    account.credentials()
+  }
+
+  pub fn login(account: &Account) -> (&String, &String) {
+    account.login()
+  }
+}
+
+#[test]
+fn sensitive_fields_lower_the_group_and_multi_getters() {
+  let account = account::Account::default()
+    .set_user("bob".to_string())
+    .set_token("t".to_string());
+  assert_eq!(account.user(), "bob");
+  assert_eq!(account::credentials(&account).1, "t");
+  assert_eq!(account::login(&account).1, "t");
+  assert_eq!(account.public_login().1, "t");
+}
@@ -0,0 +1,22 @@
+use viewit::viewit;
+
+#[viewit]
+#[derive(Default)]
+struct Packet {
+  #[viewit(getter(endian = "be", ty = "u32"), setter(endian = "be", ty = "u32"))]
+  len: [u8; 4],
+  #[viewit(getter(endian = "le", ty = "u16"), setter(endian = "le", ty = "u16"))]
+  crc: [u8; 2],
+  #[viewit(getter(endian = "be", ty = "f32"))]
+  ratio: [u8; 4],
+}
+
+#[test]
+fn endian() {
+  let packet = Packet::default().set_len(0x0102_0304).set_crc(0x0506);
+  assert_eq!(packet.len(), 0x0102_0304);
+  assert_eq!(packet.len, [1, 2, 3, 4]);
+  assert_eq!(packet.crc(), 0x0506);
+  assert_eq!(packet.crc, [6, 5]);
+  assert_eq!(packet.set_ratio(1.5).ratio(), 1.5);
+}
@@ -0,0 +1,28 @@
+#![deny(deprecated)]
+
+use viewit::viewit;
+
+#[viewit]
+#[derive(Default)]
+struct Platform {
+  #[cfg(unix)]
+  unix: u8,
+  #[cfg(not(unix))]
+  other: u8,
+  #[deprecated]
+  old: u8,
+}
+
+#[test]
+fn cfg_is_forwarded() {
+  #[cfg(unix)]
+  assert_eq!(*Platform::default().set_unix(2).unix(), 2);
+  #[cfg(not(unix))]
+  assert_eq!(*Platform::default().set_other(2).other(), 2);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_is_forwarded() {
+  assert_eq!(*Platform::default().set_old(1).old(), 1);
+}
@@ -0,0 +1,57 @@
+use viewit::viewit;
+
+#[viewit(track_changes)]
+#[derive(Default)]
+struct Server {
+  #[viewit(group = "addr")]
+  host: String,
+  #[viewit(group = "addr", group = "limits")]
+  port: u16,
+  #[viewit(group = "limits")]
+  max_conns: u32,
+}
+
+#[test]
+fn groups() {
+  let server = Server::default()
+    .set_addr("localhost".to_string(), 80)
+    .set_limits(81, 10);
+  assert_eq!(server.addr(), (&"localhost".to_string(), &81));
+  assert_eq!(server.limits(), (&81, &10));
+  assert_eq!(
+    server.dirty_fields().collect::<Vec<_>>(),
+    ["host", "port", "max_conns"]
+  );
+}
+
+#[viewit(getters(group_view))]
+#[derive(Default)]
+struct Rect {
+  #[viewit(group = "size")]
+  width: u32,
+  #[viewit(group = "size")]
+  height: u32,
+}
+
+#[test]
+fn group_view() {
+  let rect = Rect::default().set_size(2, 3);
+  let size: RectSize<'_> = rect.size();
+  assert_eq!((*size.width, *size.height), (2, 3));
+}
+
+#[viewit(getters(group_view))]
+#[derive(Default)]
+struct Listener {
+  #[viewit(group = "tls_opts")]
+  cert: String,
+  #[viewit(group = "tls_opts")]
+  key: String,
+}
+
+#[test]
+fn group_view_name() {
+  let listener = Listener::default().set_tls_opts("cert".to_string(), "key".to_string());
+  let opts: ListenerTlsOpts<'_> = listener.tls_opts();
+  assert_eq!((opts.cert.as_str(), opts.key.as_str()), ("cert", "key"));
+}
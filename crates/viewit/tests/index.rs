@@ -0,0 +1,27 @@
+use viewit::viewit;
+
+#[viewit(track_changes, setters(style = "ref"))]
+#[derive(Default)]
+struct Board {
+  #[viewit(getter(index))]
+  slots: [u8; 3],
+  #[viewit(getter(index), setter(skip))]
+  fixed: [u8; 2],
+}
+
+#[test]
+fn index() {
+  let mut board = Board::default();
+  assert_eq!(Board::SLOTS_LEN, 3);
+  assert_eq!(board.slots_at(0), Some(&0));
+  assert_eq!(board.slots_at(3), None);
+  *board.slots_at_mut(1).unwrap() = 4;
+  board.set_slots_at(2, 5).unwrap();
+  assert_eq!(board.slots(), &[0, 4, 5]);
+  assert_eq!(
+    board.set_slots_at(3, 6),
+    Err(BoardIndexError { index: 3, len: 3 })
+  );
+  assert!(board.is_dirty_slots());
+  assert_eq!(board.fixed_at(1), Some(&0));
+}
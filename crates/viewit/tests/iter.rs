@@ -0,0 +1,43 @@
+use std::collections::{BTreeMap, HashSet};
+use viewit::viewit;
+
+#[viewit(setters(style = "ref"))]
+#[derive(Default)]
+struct Store {
+  #[viewit(getter(iter))]
+  items: Vec<u32>,
+  #[viewit(getter(iter))]
+  names: BTreeMap<String, u32>,
+  #[viewit(getter(iter))]
+  ids: HashSet<u32>,
+}
+
+#[test]
+fn iter() {
+  let mut store = Store::default();
+  store.set_items(vec![1, 2]);
+  store.set_names(BTreeMap::from([("a".to_string(), 1)]));
+  assert_eq!(store.items().copied().collect::<Vec<_>>(), [1, 2]);
+  store.items_mut().for_each(|item| *item *= 10);
+  assert_eq!(store.items().copied().collect::<Vec<_>>(), [10, 20]);
+  assert_eq!(store.items_len(), 2);
+  assert!(!store.items_is_empty());
+  assert_eq!(store.names().collect::<Vec<_>>(), [(&"a".to_string(), &1)]);
+  store.names_mut().for_each(|(_, v)| *v += 1);
+  assert_eq!(store.names().next().map(|(_, v)| *v), Some(2));
+  assert!(store.ids_is_empty());
+}
+
+#[viewit(track_changes)]
+#[derive(Default)]
+struct Tracked {
+  #[viewit(getter(iter))]
+  items: Vec<u32>,
+}
+
+#[test]
+fn iter_mut_marks_the_field_dirty() {
+  let mut tracked = Tracked::default();
+  tracked.items_mut().for_each(drop);
+  assert!(tracked.is_dirty_items());
+}
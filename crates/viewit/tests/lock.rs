@@ -0,0 +1,37 @@
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use viewit::viewit;
+
+#[viewit]
+#[derive(Default)]
+struct Stats {
+  #[viewit(getter(lock))]
+  hits: Mutex<u32>,
+  #[viewit(getter(lock = "result"))]
+  cache: RwLock<Vec<u8>>,
+  #[viewit(getter(lock = "into_inner"))]
+  last: Mutex<u8>,
+}
+
+#[test]
+fn lock() {
+  let stats = Stats::default();
+  *stats.hits() += 1;
+  assert_eq!(*stats.hits(), 1);
+  stats.write_cache().unwrap().push(1);
+  assert_eq!(*stats.read_cache().unwrap(), [1]);
+}
+
+#[test]
+fn poisoned_lock() {
+  let stats = Arc::new(Stats::default());
+  let poisoner = stats.clone();
+  let _ = thread::spawn(move || {
+    let _guard = poisoner.last();
+    panic!("poison the lock");
+  })
+  .join();
+  // `into_inner` ignores the poisoning
+  *stats.last() = 1;
+  assert_eq!(*stats.last(), 1);
+}
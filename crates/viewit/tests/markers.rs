@@ -0,0 +1,50 @@
+use std::marker::{PhantomData, PhantomPinned};
+use viewit::viewit;
+
+#[viewit]
+#[derive(Default)]
+struct Typed<T> {
+  value: u8,
+  marker: PhantomData<T>,
+  pinned: PhantomPinned,
+  #[viewit(getter(skip = false))]
+  opt_in: PhantomData<T>,
+}
+
+#[viewit(getters(skip_types = "Vec"))]
+#[derive(Default)]
+struct Custom {
+  #[viewit(setter(skip = false))]
+  list: Vec<u8>,
+  marker: PhantomData<u8>,
+}
+
+// the skipped accessors do not exist, so these names are free
+impl<T> Typed<T> {
+  fn marker(&self) -> &'static str {
+    "marker"
+  }
+
+  fn pinned(&self) -> &PhantomPinned {
+    &self.pinned
+  }
+}
+
+impl Custom {
+  fn list(&self) -> usize {
+    self.list.len()
+  }
+}
+
+#[test]
+fn marker_fields_are_skipped() {
+  let typed = Typed::<String>::default().set_value(1);
+  assert_eq!(*typed.value(), 1);
+  assert_eq!(typed.marker(), "marker");
+  let _: &PhantomPinned = typed.pinned();
+  let _: &PhantomData<String> = typed.opt_in();
+
+  let custom = Custom::default().set_list(vec![1, 2]);
+  assert_eq!(custom.list(), 2);
+  let _: &PhantomData<u8> = custom.marker();
+}
@@ -0,0 +1,26 @@
+use viewit::viewit;
+
+#[viewit(
+  track_changes,
+  multi(name = "endpoint", fields = "host, port"),
+  multi(name = "host_and_id", fields = "host, id")
+)]
+#[derive(Default)]
+struct Server {
+  host: String,
+  port: u16,
+  #[viewit(setter(skip))]
+  id: u32,
+}
+
+#[test]
+fn multi() {
+  let mut server = Server::default().set_endpoint(("a".to_string(), 1));
+  let (host, port) = server.endpoint_mut();
+  host.push('b');
+  *port += 1;
+  assert_eq!(server.endpoint(), (&"ab".to_string(), &2));
+  assert_eq!(server.dirty_fields().collect::<Vec<_>>(), ["host", "port"]);
+  // no setter when a field has its setter skipped
+  assert_eq!(server.host_and_id(), (&"ab".to_string(), &0));
+}
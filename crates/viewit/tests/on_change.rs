@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use viewit::viewit;
+
+#[viewit(setters(style = "ref", replace, on_change = "Self::notify"))]
+#[derive(Default)]
+struct Counter {
+  count: u32,
+  #[viewit(setter(on_change(fn = "Self::notify", partial_eq)))]
+  name: String,
+  #[viewit(bits(name = "low", range = "0..4", ty = "u8"))]
+  flags: u8,
+  log: RefCell<Vec<String>>,
+}
+
+impl Counter {
+  fn notify<T: Debug>(&self, field: &str, old: &T, new: &T) {
+    self
+      .log
+      .borrow_mut()
+      .push(format!("{field}: {old:?} -> {new:?}"));
+  }
+}
+
+#[test]
+fn setters_call_the_hook() {
+  let mut counter = Counter::default();
+  counter.set_count(1);
+  counter.replace_count(2);
+  counter.set_name("a".to_string());
+  // unchanged, skipped by `partial_eq`
+  counter.set_name("a".to_string());
+  // the bits setters pass the whole field
+  counter.set_low(5);
+  assert_eq!(
+    counter.log.take(),
+    [
+      "count: 0 -> 1",
+      "count: 1 -> 2",
+      "name: \"\" -> \"a\"",
+      "flags: 0 -> 5",
+    ]
+  );
+}
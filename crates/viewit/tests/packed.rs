@@ -0,0 +1,28 @@
+use viewit::viewit;
+
+#[viewit(setters(replace))]
+#[repr(C, packed)]
+#[derive(Clone, Copy, Default)]
+struct Packet {
+  kind: u8,
+  #[viewit(getter(endian = "be", ty = "u32"), setter(endian = "be", ty = "u32"))]
+  len: [u8; 4],
+  #[viewit(bits(name = "urgent", bit = 0))]
+  flags: u8,
+  id: u64,
+}
+
+#[test]
+fn packed() {
+  let mut packet = Packet::default()
+    .set_kind(1)
+    .set_len(0x0102_0304)
+    .set_urgent(true)
+    .set_id(u64::MAX);
+  assert_eq!(packet.kind(), 1);
+  assert_eq!(packet.len(), 0x0102_0304);
+  assert_eq!({ packet.len }, [1, 2, 3, 4]);
+  assert!(packet.urgent());
+  assert_eq!(packet.replace_id(2), u64::MAX);
+  assert_eq!(packet.id(), 2);
+}
@@ -0,0 +1,69 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use viewit::viewit;
+
+#[viewit(getters(skip), setters(skip))]
+struct Counted<F> {
+  #[viewit(pin)]
+  inner: F,
+  polls: usize,
+}
+
+impl<F: Future> Future for Counted<F> {
+  type Output = (F::Output, usize);
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    *self.as_mut().polls_pin_mut() += 1;
+    match self.as_mut().inner_pin_mut().poll(cx) {
+      Poll::Ready(val) => Poll::Ready((val, self.polls)),
+      Poll::Pending => Poll::Pending,
+    }
+  }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+  use std::task::{RawWaker, RawWakerVTable, Waker};
+
+  fn raw() -> RawWaker {
+    RawWaker::new(std::ptr::null(), &VTABLE)
+  }
+  static VTABLE: RawWakerVTable = RawWakerVTable::new(|_| raw(), |_| {}, |_| {}, |_| {});
+  let waker = unsafe { Waker::from_raw(raw()) };
+  let mut cx = Context::from_waker(&waker);
+  let mut fut = Box::pin(fut);
+  loop {
+    if let Poll::Ready(val) = fut.as_mut().poll(&mut cx) {
+      return val;
+    }
+  }
+}
+
+#[test]
+fn pin_projections() {
+  let counted = Counted {
+    inner: async { 1 },
+    polls: 0,
+  };
+  assert_eq!(block_on(counted), (1, 1));
+}
+
+#[viewit(getters(skip), setters(skip), track_changes)]
+#[derive(Default)]
+struct Tracked {
+  #[viewit(pin)]
+  inner: String,
+  polls: usize,
+}
+
+#[test]
+fn pin_projections_mark_dirty() {
+  let mut tracked = Box::pin(Tracked::default());
+  assert_eq!(tracked.dirty_fields().count(), 0);
+  tracked.as_mut().inner_pin_mut().push('a');
+  *tracked.as_mut().polls_pin_mut() += 1;
+  assert_eq!(
+    tracked.dirty_fields().collect::<Vec<_>>(),
+    ["inner", "polls"]
+  );
+}
@@ -0,0 +1,60 @@
+mod shapes {
+  use viewit::viewit;
+
+  #[viewit(vis_all = "pub", readonly)]
+  #[derive(Default)]
+  pub struct Point {
+    x: i32,
+    #[viewit(setter(vis = "pub(crate)"))]
+    y: i32,
+  }
+
+  #[viewit(vis_all = "pub")]
+  #[derive(Default)]
+  pub struct Size {
+    #[viewit(readonly = "pub(crate)")]
+    pub width: u32,
+    pub height: u32,
+  }
+}
+
+#[test]
+fn readonly() {
+  let point = shapes::Point::default().set_y(2);
+  assert_eq!((*point.x(), *point.y()), (0, 2));
+  let size = shapes::Size::default().set_width(3);
+  assert_eq!(size.width, 3);
+  assert_eq!(*size.height(), 0);
+}
+
+mod lines {
+  use viewit::viewit;
+
+  #[viewit(vis_all = "pub", readonly, getters(take))]
+  #[derive(Default)]
+  pub struct Line {
+    #[viewit(getter(iter))]
+    points: Vec<i32>,
+  }
+
+  impl Line {
+    pub fn push(&mut self, p: i32) {
+      self.points_mut().for_each(|x| *x += p);
+      self.points.push(p);
+    }
+
+    pub fn clear(&mut self) -> Vec<i32> {
+      self.take_points()
+    }
+  }
+}
+
+#[test]
+fn readonly_mutators() {
+  let mut line = lines::Line::default();
+  line.push(1);
+  line.push(2);
+  assert_eq!(line.points().copied().collect::<Vec<_>>(), [3, 2]);
+  assert_eq!(line.clear(), [3, 2]);
+  assert_eq!(line.points_len(), 0);
+}
@@ -0,0 +1,16 @@
+use std::ops::Range;
+
+viewit::remote! {
+  #[viewit(getters(style = "move"), setters(prefix = "with"))]
+  pub struct std::ops::Range<u32> as RangeAccess {
+    start: u32,
+    #[viewit(getter(rename = "stop"))]
+    end: u32,
+  }
+}
+
+#[test]
+fn remote() {
+  let range = Range { start: 1, end: 2 }.with_start(0).with_end(4);
+  assert_eq!((range.start(), range.stop()), (0, 4));
+}
@@ -0,0 +1,17 @@
+use viewit::viewit;
+
+#[viewit(getters(allow_shadow = "clone, new"))]
+#[derive(Default)]
+struct Config {
+  #[viewit(getter(style = "move", rename = "clone"))]
+  port: u16,
+  #[viewit(getter(style = "move"))]
+  new: bool,
+}
+
+#[test]
+fn shadowing_is_allowed_when_listed() {
+  let config = Config::default().set_port(8080).set_new(true);
+  assert_eq!(config.clone(), 8080);
+  assert!(config.new());
+}
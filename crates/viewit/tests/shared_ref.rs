@@ -0,0 +1,42 @@
+use viewit::viewit;
+
+#[viewit]
+struct Parser<'a> {
+  input: &'a str,
+  prev: Option<&'a str>,
+  pos: usize,
+}
+
+// the returned references outlive the borrow of the parser
+fn rest<'a>(parser: &Parser<'a>) -> (&'a str, Option<&'a str>) {
+  (parser.input(), parser.prev())
+}
+
+#[test]
+fn shared_ref_getters_return_by_copy() {
+  let parser = Parser {
+    input: "hello",
+    prev: Some("he"),
+    pos: 0,
+  };
+  let input: &str = parser.input();
+  let prev: Option<&str> = parser.prev();
+  assert_eq!((input, prev, *parser.pos()), ("hello", Some("he"), 0));
+}
+
+#[test]
+fn shared_ref_getters_keep_the_lifetime() {
+  let input = String::from("hello world");
+  let (rest, prev) = {
+    let parser = Parser {
+      input: &input,
+      prev: None,
+      pos: 0,
+    }
+    .set_prev(Some(&input[..5]));
+    assert_eq!(*parser.pos(), 0);
+    rest(&parser)
+  };
+  assert_eq!(rest, "hello world");
+  assert_eq!(prev, Some("hello"));
+}
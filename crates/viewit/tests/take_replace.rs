@@ -0,0 +1,19 @@
+use viewit::viewit;
+
+#[viewit(getters(take), setters(replace))]
+#[derive(Default)]
+struct Config {
+  name: String,
+  #[viewit(getter(take = false), setter(replace = false))]
+  port: u16,
+}
+
+#[test]
+fn take_and_replace() {
+  let mut config = Config::default().set_name("a".to_string());
+  assert_eq!(config.replace_name("b".to_string()), "a");
+  assert_eq!(config.name(), "b");
+  assert_eq!(config.take_name(), "b");
+  assert_eq!(config.name(), "");
+  assert_eq!(*config.set_port(1).port(), 1);
+}
@@ -0,0 +1,48 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use viewit::viewit;
+
+#[viewit(track_changes, getters(take), setters(style = "ref", replace))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Doc {
+  title: String,
+  body: String,
+  views: u32,
+}
+
+fn hash(doc: &Doc) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  doc.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[test]
+fn mutators_mark_the_fields_dirty() {
+  let mut doc = Doc::default();
+  assert_eq!(doc.dirty_fields().count(), 0);
+
+  doc.set_title("a".to_string());
+  assert!(doc.is_dirty_title());
+  assert!(!doc.is_dirty_body());
+
+  doc.replace_views(1);
+  doc.take_body();
+  assert_eq!(
+    doc.dirty_fields().collect::<Vec<_>>(),
+    ["title", "body", "views"]
+  );
+
+  doc.clear_dirty();
+  assert_eq!(doc.dirty_fields().count(), 0);
+}
+
+#[test]
+fn dirty_bits_are_ignored_by_derives() {
+  let clean = Doc::default();
+  let mut dirty = Doc::default();
+  dirty.set_title(String::new());
+  assert!(dirty.is_dirty_title());
+  assert_eq!(clean, dirty);
+  assert_eq!(clean.cmp(&dirty), std::cmp::Ordering::Equal);
+  assert_eq!(hash(&clean), hash(&dirty));
+}
@@ -0,0 +1,78 @@
+use std::fmt::Display;
+use std::num::TryFromIntError;
+use viewit::viewit;
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+  Range,
+  Field(&'static str, String),
+}
+
+impl ConfigError {
+  fn field<E: Display>(name: &'static str) -> impl FnOnce(E) -> Self {
+    move |e| Self::Field(name, e.to_string())
+  }
+}
+
+impl From<TryFromIntError> for ConfigError {
+  fn from(_: TryFromIntError) -> Self {
+    Self::Range
+  }
+}
+
+#[viewit(setters(style = "try_into", error = "ConfigError"))]
+#[derive(Default)]
+struct Config {
+  port: u16,
+  #[viewit(setter(map_err = "|_| ConfigError::Field(\"level\", String::new())"))]
+  level: u8,
+  #[viewit(setter(
+    map_err = "ConfigError::field(\"id\")",
+    where = "__ViewitTryIntoError: Display"
+  ))]
+  id: u16,
+}
+
+#[test]
+fn error_type() {
+  assert_eq!(*Config::default().set_port(80u32).unwrap().port(), 80);
+  assert_eq!(
+    Config::default().set_port(70000u32).err(),
+    Some(ConfigError::Range)
+  );
+  assert_eq!(
+    Config::default().set_level(300u32).err(),
+    Some(ConfigError::Field("level", String::new()))
+  );
+}
+
+#[test]
+fn map_err_keeps_the_source() {
+  let source = u16::try_from(70000u32).unwrap_err().to_string();
+  assert_eq!(
+    Config::default().set_id(70000u32).err(),
+    Some(ConfigError::Field("id", source))
+  );
+}
+
+mod shadow {
+  use viewit::viewit;
+
+  #[derive(Debug, PartialEq)]
+  pub struct Error(pub &'static str);
+
+  #[viewit(setters(style = "try_into", error = "Error"))]
+  #[derive(Default)]
+  pub struct Server {
+    #[viewit(setter(map_err = "|_| Error(\"port\")"))]
+    port: u16,
+  }
+}
+
+#[test]
+fn map_err_to_error_named_error() {
+  assert_eq!(
+    shadow::Server::default().set_port(70000u32).err(),
+    Some(shadow::Error("port"))
+  );
+}
@@ -0,0 +1,13 @@
+use viewit::viewit;
+
+#[viewit(getters(prefix = "get", unknown), computed(name = "x"))]
+struct Foo {
+  #[viewit(getter(iter))]
+  a: u8,
+  #[viewit(setter(style = "nope"))]
+  b: u8,
+  #[viewit(bits(name = "c", bit = 9))]
+  c: u8,
+}
+
+fn main() {}
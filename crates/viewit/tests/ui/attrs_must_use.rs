@@ -0,0 +1,17 @@
+#![deny(unused_must_use)]
+
+use viewit::viewit;
+
+#[viewit(track_changes, getters(attrs(must_use)))]
+#[derive(Default)]
+struct Config {
+  port: u16,
+}
+
+fn main() {
+  let config = Config::default();
+  config.port();
+  // the struct level attrs also apply to the change tracker fns
+  config.is_dirty_port();
+  config.dirty_fields();
+}
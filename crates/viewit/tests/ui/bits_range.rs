@@ -0,0 +1,17 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(bits(name = "wide", range = "0..12", ty = "u8"))]
+  a: u16,
+  #[viewit(bits(name = "outside", range = "12..20"))]
+  b: u16,
+  #[viewit(bits(name = "empty", range = "4..4"))]
+  c: u16,
+  #[viewit(bits(name = "both", range = "0..2", bit = 3))]
+  d: u16,
+  #[viewit(bits(name = "float", bit = 0))]
+  e: f32,
+}
+
+fn main() {}
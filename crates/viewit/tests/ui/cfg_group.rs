@@ -0,0 +1,12 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(group = "both")]
+  a: u8,
+  #[cfg(any())]
+  #[viewit(group = "both")]
+  b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use viewit::viewit;
+
+#[viewit(multi(name = "both", fields = "a, b"))]
+struct Foo {
+  a: u8,
+  #[cfg(any())]
+  b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(setter(style = "cow"))]
+  a: Vec<u8>,
+}
+
+fn main() {}
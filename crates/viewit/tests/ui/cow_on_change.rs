@@ -0,0 +1,14 @@
+use std::sync::Arc;
+use viewit::viewit;
+
+#[viewit(setters(on_change = "Self::notify"))]
+struct Foo {
+  #[viewit(setter(style = "cow"))]
+  a: Arc<Vec<u8>>,
+}
+
+impl Foo {
+  fn notify<T>(&self, _: &str, _: &T, _: &T) {}
+}
+
+fn main() {}
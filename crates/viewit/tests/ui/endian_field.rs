@@ -0,0 +1,15 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(getter(endian = "be"))]
+  d: [u8; 4],
+}
+
+#[viewit]
+struct Bar {
+  #[viewit(getter(endian = "be", ty = "u32"))]
+  e: u32,
+}
+
+fn main() {}
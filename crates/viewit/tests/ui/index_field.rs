@@ -0,0 +1,9 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(getter(index))]
+  c: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use viewit::viewit;
+
+#[viewit(setters(on_change = "Self::notify"))]
+struct Foo {
+  #[viewit(getter(index))]
+  a: [u8; 4],
+}
+
+impl Foo {
+  fn notify<T>(&self, _: &str, _: &T, _: &T) {}
+}
+
+fn main() {}
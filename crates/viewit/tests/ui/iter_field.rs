@@ -0,0 +1,9 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(getter(iter))]
+  a: [u8; 4],
+}
+
+fn main() {}
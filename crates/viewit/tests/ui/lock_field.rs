@@ -0,0 +1,9 @@
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(getter(lock))]
+  b: Vec<u8>,
+}
+
+fn main() {}
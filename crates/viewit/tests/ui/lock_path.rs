@@ -0,0 +1,27 @@
+mod parking_lot {
+  pub struct Mutex<T>(pub T);
+}
+
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(getter(lock))]
+  a: parking_lot::Mutex<u8>,
+  #[viewit(getter(lock))]
+  b: std::sync::Mutex<u8>,
+  #[viewit(getter(lock))]
+  c: ::std::sync::RwLock<u8>,
+}
+
+#[viewit(setters(on_change = "Self::notify"))]
+struct Bar {
+  #[viewit(getter(lock))]
+  a: std::sync::Mutex<u8>,
+}
+
+impl Bar {
+  fn notify<T>(&self, _: &str, _: &T, _: &T) {}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use viewit::viewit;
+
+#[viewit(
+  multi(name = "unknown", fields = "a, c"),
+  multi(name = "twice", fields = "a, b, a"),
+  multi(name = "empty", fields = "")
+)]
+struct Foo {
+  a: u8,
+  b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use viewit::viewit;
+
+#[viewit(computed(name = "a", ty = "u8", expr = "self.a"))]
+struct Foo {
+  a: u8,
+  #[viewit(getter(rename = "a"))]
+  b: u8,
+}
+
+#[viewit]
+struct Bar {
+  a: u8,
+  #[viewit(getter(rename = "clone"))]
+  b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use viewit::viewit;
+
+#[viewit]
+#[repr(packed)]
+struct Foo {
+  #[viewit(group = "both")]
+  a: u8,
+  #[viewit(group = "both")]
+  b: u16,
+}
+
+fn main() {}
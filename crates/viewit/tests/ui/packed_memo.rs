@@ -0,0 +1,10 @@
+use viewit::viewit;
+
+#[viewit(computed(name = "double", ty = "u16", expr = "self.a as u16 * 2", memo))]
+#[repr(packed)]
+#[derive(Clone, Copy)]
+struct Foo {
+  a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use viewit::viewit;
+
+#[viewit(multi(name = "ab", fields = "a, b"))]
+#[repr(packed)]
+struct Foo {
+  a: u8,
+  b: u16,
+}
+
+fn main() {}
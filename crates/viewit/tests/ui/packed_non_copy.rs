@@ -0,0 +1,10 @@
+use viewit::viewit;
+
+#[viewit]
+#[repr(packed)]
+struct Foo {
+  a: u8,
+  b: String,
+}
+
+fn main() {}
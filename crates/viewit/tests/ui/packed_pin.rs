@@ -0,0 +1,10 @@
+use viewit::viewit;
+
+#[viewit]
+#[repr(packed)]
+struct Foo {
+  #[viewit(pin)]
+  a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use std::marker::PhantomPinned;
+use viewit::viewit;
+
+#[viewit]
+struct Foo {
+  #[viewit(pin)]
+  a: PhantomPinned,
+  b: u8,
+}
+
+impl Drop for Foo {
+  fn drop(&mut self) {}
+}
+
+fn main() {}
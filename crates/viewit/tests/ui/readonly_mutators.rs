@@ -0,0 +1,32 @@
+mod shapes {
+  use core::pin::Pin;
+  use viewit::viewit;
+
+  #[viewit(vis_all = "pub", readonly, getters(take))]
+  #[derive(Default)]
+  pub struct Shape {
+    #[viewit(getter(iter))]
+    points: Vec<i32>,
+    #[viewit(getter(index))]
+    sides: [u8; 4],
+    name: String,
+    #[viewit(pin)]
+    count: u8,
+  }
+
+  impl Shape {
+    pub fn pinned(self: Pin<&mut Self>) -> &mut String {
+      self.name_pin_mut()
+    }
+  }
+}
+
+fn main() {
+  let mut shape = shapes::Shape::default();
+  let _ = shape.points();
+  let _ = shape.sides_at(0);
+  shape.points_mut();
+  shape.sides_at_mut(0);
+  shape.take_name();
+  core::pin::Pin::new(&mut shape).name_pin_mut();
+}
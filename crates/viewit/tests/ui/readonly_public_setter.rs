@@ -0,0 +1,9 @@
+use viewit::viewit;
+
+#[viewit]
+pub struct Foo {
+  #[viewit(readonly, setter(vis = "pub"))]
+  pub a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+viewit::remote! {
+  pub struct std::ops::Range<u32> as RangeAccess {
+    start: u64,
+    stop: u32,
+  }
+}
+
+fn main() {}
@@ -0,0 +1,86 @@
+mod track_changes {
+  viewit::remote! {
+    #[viewit(track_changes)]
+    pub struct std::ops::Range<u32> as RangeAccess {
+      start: u32,
+    }
+  }
+}
+
+mod readonly {
+  viewit::remote! {
+    #[viewit(readonly)]
+    pub struct std::ops::Range<u32> as RangeAccess {
+      start: u32,
+    }
+  }
+}
+
+mod memo {
+  viewit::remote! {
+    #[viewit(computed(name = "len", ty = "u32", expr = "self.end - self.start", memo))]
+    pub struct std::ops::Range<u32> as RangeAccess {
+      start: u32,
+    }
+  }
+}
+
+mod debug_impl {
+  viewit::remote! {
+    #[viewit(debug_impl)]
+    pub struct std::ops::Range<u32> as RangeAccess {
+      start: u32,
+    }
+  }
+}
+
+mod sensitive {
+  viewit::remote! {
+    pub struct std::ops::Range<u32> as RangeAccess {
+      #[viewit(sensitive)]
+      start: u32,
+    }
+  }
+}
+
+mod pin {
+  viewit::remote! {
+    pub struct std::ops::Range<u32> as RangeAccess {
+      #[viewit(pin)]
+      start: u32,
+    }
+  }
+}
+
+mod group_view {
+  viewit::remote! {
+    #[viewit(getters(group_view))]
+    pub struct std::ops::Range<u32> as RangeAccess {
+      start: u32,
+    }
+  }
+}
+
+mod impl_where {
+  viewit::remote! {
+    pub struct std::ops::Range<u32> as RangeAccess {
+      #[viewit(getter(impl_where = "u32: Copy"))]
+      start: u32,
+    }
+  }
+}
+
+mod several {
+  viewit::remote! {
+    #[viewit(track_changes, debug_impl)]
+    #[viewit(getters(group_view))]
+    pub struct std::ops::Range<u32> as RangeAccess {
+      #[viewit(pin)]
+      start: u32,
+      #[viewit(sensitive)]
+      end: u32,
+    }
+  }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+mod login {
+  use viewit::viewit;
+
+  #[viewit(vis_all = "pub", multi(name = "both", fields = "user, password"))]
+  #[derive(Default)]
+  pub struct Login {
+    #[viewit(group = "credentials")]
+    user: String,
+    #[viewit(group = "credentials", sensitive)]
+    password: String,
+    #[viewit(sensitive, getter(vis = "pub"))]
+    hint: String,
+  }
+}
+
+fn main() {
+  let mut login = login::Login::default();
+  let _ = login.user();
+  let _ = login.hint();
+  let _ = login.password();
+  let _ = login.credentials();
+  let _ = login.both();
+  let _ = login.both_mut();
+}
@@ -0,0 +1,17 @@
+use viewit::viewit;
+
+#[viewit(setters(style = "try_into"))]
+struct Foo {
+  #[viewit(setter(map_err = "|_| ()"))]
+  a: u8,
+}
+
+#[viewit]
+struct Bar {
+  #[viewit(setter(error = "()"))]
+  a: u8,
+  #[viewit(setter(map_err = "|_| ()"))]
+  b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use viewit::viewit;
+
+#[viewit]
+#[derive(Default)]
+struct Wrapper<T> {
+  #[viewit(getter(style = "move", where = "T: Copy"))]
+  value: T,
+  #[viewit(
+    getter(style = "move", impl_where = "T: Copy"),
+    setter(impl_where = "T: Default")
+  )]
+  other: T,
+}
+
+#[test]
+fn where_clauses() {
+  let wrapper = Wrapper::<u8>::default().set_value(1).set_other(2);
+  assert_eq!(wrapper.value(), 1);
+  assert_eq!(wrapper.other(), 2);
+
+  // `String` is not `Copy`, the other accessors are still available
+  let wrapper = Wrapper::<String>::default().set_value("a".to_string());
+  assert_eq!(wrapper.value, "a");
+}